@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yulid::Ulid;
+
+const TEST_BASE32: &str = "05kzbnmt1hnwhs62crxermqqaw";
+
+fn parse(c: &mut Criterion) {
+  c.bench_function("parse_str", |b| b.iter(|| Ulid::parse_str(black_box(TEST_BASE32))));
+}
+
+fn format(c: &mut Criterion) {
+  let ulid = Ulid::parse_str(TEST_BASE32).unwrap();
+
+  c.bench_function("to_string", |b| b.iter(|| black_box(ulid).to_string()));
+}
+
+#[cfg(feature = "rand")]
+fn generate(c: &mut Criterion) {
+  use std::time::SystemTime;
+
+  c.bench_function("new", |b| b.iter(Ulid::new));
+
+  let now = SystemTime::now();
+  c.bench_function("from_system_time", |b| b.iter(|| Ulid::from_system_time(black_box(now))));
+}
+
+#[cfg(not(feature = "rand"))]
+fn generate(_: &mut Criterion) {}
+
+criterion_group!(benches, parse, format, generate);
+criterion_main!(benches);
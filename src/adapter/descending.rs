@@ -0,0 +1,139 @@
+//! Adapters for formatting [`Ulid`](crate::Ulid)s as descending sort keys.
+
+use crate::{ParseError, Ulid};
+
+use core::cmp::Ordering;
+
+/// An adapter for formatting a [`Ulid`] as a descending sort key string.
+///
+/// Sorting these strings in ascending order yields the [`Ulid`]s newest-first. Like
+/// [`Reverse`](core::cmp::Reverse), the adapter itself also compares in reverse order.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Descending(Ulid);
+
+/// An adapter for formatting a [`Ulid`] as a descending sort key string.
+///
+/// Sorting these strings in ascending order yields the [`Ulid`]s newest-first. Like
+/// [`Reverse`](core::cmp::Reverse), the adapter itself also compares in reverse order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DescendingRef<'a>(&'a Ulid);
+
+impl Ulid {
+  /// Creates a [`Descending`] instance from a [`Ulid`].
+  pub const fn to_descending(self) -> Descending {
+    Descending::from_ulid(self)
+  }
+
+  /// Creates a [`DescendingRef`] instance from a [`Ulid`].
+  pub const fn to_descending_ref(&self) -> DescendingRef<'_> {
+    DescendingRef::from_ulid(self)
+  }
+
+  /// Parses a [`Ulid`] from a descending sort key string created by [`Descending`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+  /// let key = ulid.to_descending().to_string();
+  ///
+  /// assert_eq!(Ok(ulid), Ulid::parse_descending_str(&key));
+  /// ```
+  pub fn parse_descending_str(input: &str) -> Result<Self, ParseError> {
+    Ulid::parse_str(input).map(|key| Ulid::from_descending_key(*key.as_bytes()))
+  }
+}
+
+impl Descending {
+  /// Creates a [`Descending`] instance from a [`Ulid`].
+  pub const fn from_ulid(ulid: Ulid) -> Self {
+    Descending(ulid)
+  }
+
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &Ulid {
+    &self.0
+  }
+
+  /// Consumes the adapter, returning the underlying [`Ulid`].
+  pub const fn into_ulid(self) -> Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
+    crate::parser::encode(
+      crate::parser::Case::Lower,
+      &self.0.to_descending_key(),
+    )
+  }
+}
+
+impl<'a> DescendingRef<'a> {
+  /// Creates a [`DescendingRef`] instance from a [`Ulid`].
+  pub const fn from_ulid(ulid: &'a Ulid) -> Self {
+    DescendingRef(ulid)
+  }
+
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &'a Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
+    crate::parser::encode(
+      crate::parser::Case::Lower,
+      &self.0.to_descending_key(),
+    )
+  }
+}
+
+impl PartialOrd for Descending {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Descending {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.0.cmp(&self.0)
+  }
+}
+
+impl PartialOrd for DescendingRef<'_> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for DescendingRef<'_> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.0.cmp(self.0)
+  }
+}
+
+impl From<Ulid> for Descending {
+  #[inline]
+  fn from(ulid: Ulid) -> Self {
+    Descending::from_ulid(ulid)
+  }
+}
+
+impl From<Descending> for Ulid {
+  #[inline]
+  fn from(adapter: Descending) -> Self {
+    adapter.into_ulid()
+  }
+}
+
+impl<'a> From<&'a Ulid> for DescendingRef<'a> {
+  #[inline]
+  fn from(ulid: &'a Ulid) -> Self {
+    DescendingRef::from_ulid(ulid)
+  }
+}
@@ -2,9 +2,6 @@
 
 use crate::Ulid;
 
-#[cfg(not(feature = "std"))]
-use alloc::string::String;
-
 /// An adapter for formatting a [`Ulid`] as an lowercase string.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Lowercase(Ulid);
@@ -31,7 +28,17 @@ impl Lowercase {
     Lowercase(ulid)
   }
 
-  pub(crate) fn encode(self) -> String {
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &Ulid {
+    &self.0
+  }
+
+  /// Consumes the adapter, returning the underlying [`Ulid`].
+  pub const fn into_ulid(self) -> Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
     crate::parser::encode(
       crate::parser::Case::Lower,
       self.0.as_bytes(),
@@ -45,10 +52,36 @@ impl<'a> LowercaseRef<'a> {
     LowercaseRef(ulid)
   }
 
-  pub(crate) fn encode(self) -> String {
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &'a Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
     crate::parser::encode(
       crate::parser::Case::Lower,
       self.0.as_bytes(),
     )
   }
 }
+
+impl From<Ulid> for Lowercase {
+  #[inline]
+  fn from(ulid: Ulid) -> Self {
+    Lowercase::from_ulid(ulid)
+  }
+}
+
+impl From<Lowercase> for Ulid {
+  #[inline]
+  fn from(adapter: Lowercase) -> Self {
+    adapter.into_ulid()
+  }
+}
+
+impl<'a> From<&'a Ulid> for LowercaseRef<'a> {
+  #[inline]
+  fn from(ulid: &'a Ulid) -> Self {
+    LowercaseRef::from_ulid(ulid)
+  }
+}
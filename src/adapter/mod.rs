@@ -1,9 +1,11 @@
 //! Adapters for various formats for [`Ulid`]s.
 
+pub mod descending;
 pub mod lowercase;
 pub mod uppercase;
 
 pub use self::{
+  descending::{Descending, DescendingRef},
   lowercase::{Lowercase, LowercaseRef},
   uppercase::{Uppercase, UppercaseRef},
 };
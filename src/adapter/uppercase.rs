@@ -2,9 +2,6 @@
 
 use crate::Ulid;
 
-#[cfg(not(feature = "std"))]
-use alloc::string::String;
-
 /// An adapter for formatting a [`Ulid`] as an uppercase string.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Uppercase(Ulid);
@@ -31,7 +28,17 @@ impl Uppercase {
     Uppercase(ulid)
   }
 
-  pub(crate) fn encode(self) -> String {
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &Ulid {
+    &self.0
+  }
+
+  /// Consumes the adapter, returning the underlying [`Ulid`].
+  pub const fn into_ulid(self) -> Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
     crate::parser::encode(
       crate::parser::Case::Upper,
       self.0.as_bytes(),
@@ -45,10 +52,36 @@ impl<'a> UppercaseRef<'a> {
     UppercaseRef(ulid)
   }
 
-  pub(crate) fn encode(self) -> String {
+  /// Returns a reference to the underlying [`Ulid`].
+  pub const fn as_ulid(&self) -> &'a Ulid {
+    self.0
+  }
+
+  pub(crate) fn encode(self) -> crate::parser::Encoded {
     crate::parser::encode(
       crate::parser::Case::Upper,
       self.0.as_bytes(),
     )
   }
 }
+
+impl From<Ulid> for Uppercase {
+  #[inline]
+  fn from(ulid: Ulid) -> Self {
+    Uppercase::from_ulid(ulid)
+  }
+}
+
+impl From<Uppercase> for Ulid {
+  #[inline]
+  fn from(adapter: Uppercase) -> Self {
+    adapter.into_ulid()
+  }
+}
+
+impl<'a> From<&'a Ulid> for UppercaseRef<'a> {
+  #[inline]
+  fn from(ulid: &'a Ulid) -> Self {
+    UppercaseRef::from_ulid(ulid)
+  }
+}
@@ -0,0 +1,29 @@
+//! [`borsh`] implementations for [`Ulid`].
+//!
+//! A [`Ulid`] is encoded as its 16 big-endian bytes with no length prefix, the same as a borsh
+//! `[u8; 16]`.
+
+use crate::Ulid;
+
+use borsh::{
+  io::{Read, Result, Write},
+  BorshDeserialize, BorshSerialize,
+};
+
+impl BorshSerialize for Ulid {
+  fn serialize<W>(&self, writer: &mut W) -> Result<()>
+    where W: Write,
+  {
+    writer.write_all(self.as_bytes())
+  }
+}
+
+impl BorshDeserialize for Ulid {
+  fn deserialize_reader<R>(reader: &mut R) -> Result<Self>
+    where R: Read,
+  {
+    let mut bytes = [0; 16];
+    reader.read_exact(&mut bytes)?;
+    Ok(Ulid::from_bytes(bytes))
+  }
+}
@@ -0,0 +1,77 @@
+//! Time-bucket helpers for partitioning [`Ulid`]s by their timestamp.
+
+use crate::Ulid;
+
+use core::{
+  ops::RangeInclusive,
+  time::Duration,
+};
+
+impl Ulid {
+  /// Returns the index of the time bucket of width `width` that contains this [`Ulid`]'s timestamp.
+  ///
+  /// Buckets are counted from the Unix epoch, so bucket `n` covers the milliseconds
+  /// `n * width..(n + 1) * width`. [`Ulid`]s with a timestamp before the Unix epoch (see
+  /// [`Ulid::as_millis()`]) are placed in negative buckets, rounding down like
+  /// [`i64::div_euclid`].
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if `width` is less than one millisecond or is not a whole number of
+  /// milliseconds.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::time::Duration;
+  /// use yulid::Ulid;
+  ///
+  /// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+  ///
+  /// // 2018-12-28T17:22:21.324Z is 17893 days after the epoch
+  /// assert_eq!(17893, ulid.bucket(Duration::from_secs(24 * 60 * 60)));
+  /// ```
+  pub fn bucket(&self, width: Duration) -> i64 {
+    let remainder = width.subsec_nanos() % 1_000_000;
+    let width = width.as_millis();
+    assert!(width > 0, "bucket width must be at least one millisecond");
+    assert!(remainder == 0, "bucket width must be a whole number of milliseconds");
+
+    i128::from(self.as_millis()).div_euclid(width as i128) as i64
+  }
+
+  /// Returns the indices of the time buckets of width `width` covered by the [`Ulid`]s from
+  /// `start` to `end`, inclusive.
+  ///
+  /// The range is empty if `start` is after `end`. See [`Ulid::bucket()`] for how buckets are
+  /// counted.
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if `width` is less than one millisecond or is not a whole number of
+  /// milliseconds.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::time::Duration;
+  /// use yulid::Ulid;
+  ///
+  /// let start = Ulid::from_millis_bytes(3_599_999, [0; 10]);
+  /// let end = Ulid::from_millis_bytes(7_200_000, [0; 10]);
+  ///
+  /// let hours: Vec<i64> = Ulid::bucket_range(&start, &end, Duration::from_secs(60 * 60)).collect();
+  ///
+  /// assert_eq!(vec![0, 1, 2], hours);
+  /// ```
+  pub fn bucket_range(start: &Ulid, end: &Ulid, width: Duration) -> RangeInclusive<i64> {
+    if start > end {
+      // an exhausted range is empty
+      let mut empty = 0..=0;
+      empty.next();
+      return empty;
+    }
+
+    start.bucket(width)..=end.bucket(width)
+  }
+}
@@ -0,0 +1,12 @@
+//! [`bytemuck`] implementations for [`Ulid`].
+//!
+//! [`Ulid`] is `repr(transparent)` over [`Bytes`](crate::Bytes), so it can be cast to and from
+//! byte buffers the same way as a `[u8; 16]`.
+
+use crate::Ulid;
+
+// SAFETY: `Ulid` is `repr(transparent)` over `[u8; 16]`, for which all zeroes is valid.
+unsafe impl bytemuck::Zeroable for Ulid {}
+
+// SAFETY: `Ulid` is `repr(transparent)` over `[u8; 16]`, which is `Pod`.
+unsafe impl bytemuck::Pod for Ulid {}
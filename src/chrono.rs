@@ -0,0 +1,39 @@
+//! Conversions to and from [`DateTime`](chrono::DateTime)/[`Ulid`].
+
+use crate::Ulid;
+
+use chrono::{DateTime, Timelike, TimeZone, Utc};
+
+impl Ulid {
+  /// Creates a [`Ulid`] from a timestamp and the provided bytes.
+  #[inline]
+  pub fn from_timestamp_bytes<Tz: TimeZone>(timestamp: DateTime<Tz>, bytes: [u8; 10]) -> Self {
+    Ulid::from_millis_bytes(timestamp.timestamp_millis(), bytes)
+  }
+
+  /// Returns the timestamp portion of this [`Ulid`].
+  pub fn as_timestamp(&self) -> DateTime<Utc> {
+    self.as_timestamp_opt().expect("timestamp out of bounds for DateTime")
+  }
+
+  /// Returns the timestamp portion of this [`Ulid`], capturing out-of-bounds values as `None`.
+  pub fn as_timestamp_opt(&self) -> Option<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(self.as_millis()).single()
+  }
+
+  /// Returns the start of the UTC day containing the timestamp portion of this [`Ulid`].
+  ///
+  /// This is suitable as a daily partition key.
+  pub fn day_bucket(&self) -> DateTime<Utc> {
+    let timestamp = self.as_timestamp();
+    Utc.from_utc_datetime(&timestamp.date_naive().and_hms_opt(0, 0, 0).unwrap())
+  }
+
+  /// Returns the start of the UTC hour containing the timestamp portion of this [`Ulid`].
+  ///
+  /// This is suitable as an hourly partition key.
+  pub fn hour_bucket(&self) -> DateTime<Utc> {
+    let timestamp = self.as_timestamp();
+    Utc.from_utc_datetime(&timestamp.date_naive().and_hms_opt(timestamp.hour(), 0, 0).unwrap())
+  }
+}
@@ -0,0 +1,51 @@
+//! Sources of the current time for generating and checking [`Ulid`](crate::Ulid)s.
+//!
+//! With the `rand_core` feature, `Ulid::generate()` reads the timestamp from a [`TimeSource`]
+//! rather than the system clock, so that targets without `std`, such as microcontrollers with an
+//! RTC, can generate ULIDs too. With `std`, the `_with` variants of the age queries, such as
+//! `Ulid::is_older_than_with()`, compare against a [`TimeSource`], which makes them testable with a
+//! fixed clock.
+
+/// A source of the current time, in milliseconds since the Unix epoch.
+///
+/// Any closure returning an [`i64`] is a [`TimeSource`].
+///
+/// # Examples
+///
+/// ```
+/// use yulid::clock::TimeSource;
+///
+/// let mut ticks = 1_546_017_741_324;
+/// let mut clock = || {
+///   ticks += 1;
+///   ticks
+/// };
+///
+/// assert_eq!(clock.now_millis(), 1_546_017_741_325);
+/// ```
+pub trait TimeSource {
+  /// Returns the current time, in milliseconds since the Unix epoch.
+  ///
+  /// Only the low 48 bits are stored in a [`Ulid`](crate::Ulid).
+  fn now_millis(&mut self) -> i64;
+}
+
+impl<F> TimeSource for F
+  where F: FnMut() -> i64,
+{
+  fn now_millis(&mut self) -> i64 {
+    self()
+  }
+}
+
+/// A [`TimeSource`] reading the system clock through [`SystemTime`](std::time::SystemTime).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl TimeSource for SystemClock {
+  fn now_millis(&mut self) -> i64 {
+    crate::components::system_time_millis(std::time::SystemTime::now())
+  }
+}
@@ -1,17 +1,172 @@
 //! Implementations for components of a [`Ulid`] that require `std`.
 
-use crate::Ulid;
+use crate::{clock::TimeSource, Ulid};
 
-use chrono::{DateTime, TimeZone, Utc};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Returns the milliseconds since the Unix epoch of a [`SystemTime`], rounding down.
+pub(crate) fn system_time_millis(time: SystemTime) -> i64 {
+  match time.duration_since(UNIX_EPOCH) {
+    Ok(after) => after.as_millis() as i64,
+    Err(before) => {
+      let before = before.duration();
+      let millis = before.as_millis() as i64;
+      if before.subsec_nanos() % 1_000_000 == 0 {
+        -millis
+      } else {
+        -millis - 1
+      }
+    },
+  }
+}
+
+/// Returns the [`SystemTime`] `millis` milliseconds after the Unix epoch, or `None` if it is out of
+/// bounds.
+pub(crate) fn millis_system_time(millis: i64) -> Option<SystemTime> {
+  let offset = Duration::from_millis(millis.unsigned_abs());
+  if millis < 0 {
+    UNIX_EPOCH.checked_sub(offset)
+  } else {
+    UNIX_EPOCH.checked_add(offset)
+  }
+}
+
+const MILLIS_PER_HOUR: i64 = 60 * 60 * 1000;
+const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
 
 impl Ulid {
   /// Returns the timestamp portion of this [`Ulid`].
-  pub fn as_timestamp(&self) -> DateTime<Utc> {
-    Utc.timestamp_millis(self.as_millis())
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if the timestamp cannot be represented by [`SystemTime`] on this
+  /// platform. See [`Ulid::as_system_time_opt()`] for a non-panicking version.
+  pub fn as_system_time(&self) -> SystemTime {
+    self.as_system_time_opt().expect("timestamp out of bounds for SystemTime")
   }
 
   /// Returns the timestamp portion of this [`Ulid`], capturing out-of-bounds values as `None`.
-  pub fn as_timestamp_opt(&self) -> Option<DateTime<Utc>> {
-    Utc.timestamp_millis_opt(self.as_millis()).single()
+  pub fn as_system_time_opt(&self) -> Option<SystemTime> {
+    millis_system_time(self.as_millis())
+  }
+
+  /// Returns the start of the UTC day containing the timestamp portion of this [`Ulid`].
+  ///
+  /// This is suitable as a daily partition key. With the `chrono` feature, `Ulid::day_bucket()`
+  /// returns the same instant as a `DateTime<Utc>`.
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if the start of the day cannot be represented by [`SystemTime`] on
+  /// this platform.
+  pub fn day_bucket_system_time(&self) -> SystemTime {
+    let millis = self.as_millis().div_euclid(MILLIS_PER_DAY) * MILLIS_PER_DAY;
+    millis_system_time(millis).expect("timestamp out of bounds for SystemTime")
+  }
+
+  /// Returns the start of the UTC hour containing the timestamp portion of this [`Ulid`].
+  ///
+  /// This is suitable as an hourly partition key. With the `chrono` feature, `Ulid::hour_bucket()`
+  /// returns the same instant as a `DateTime<Utc>`.
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if the start of the hour cannot be represented by [`SystemTime`] on
+  /// this platform.
+  pub fn hour_bucket_system_time(&self) -> SystemTime {
+    let millis = self.as_millis().div_euclid(MILLIS_PER_HOUR) * MILLIS_PER_HOUR;
+    millis_system_time(millis).expect("timestamp out of bounds for SystemTime")
+  }
+
+  /// Returns how long ago the timestamp portion of this [`Ulid`] was.
+  ///
+  /// This returns `None` if the timestamp is in the future or out of bounds. To measure the age at
+  /// a point in time other than now, use [`Ulid::age_at()`].
+  #[inline]
+  pub fn age(&self) -> Option<Duration> {
+    self.age_at(SystemTime::now())
+  }
+
+  /// Returns how long before `now` the timestamp portion of this [`Ulid`] was.
+  ///
+  /// This returns `None` if the timestamp is after `now` or out of bounds.
+  pub fn age_at(&self, now: SystemTime) -> Option<Duration> {
+    now.duration_since(self.as_system_time_opt()?).ok()
+  }
+
+  /// Returns how long before the current time of `clock` the timestamp portion of this [`Ulid`]
+  /// was.
+  ///
+  /// This returns `None` if the timestamp is after the current time or either is out of bounds.
+  pub fn age_with<C>(&self, clock: &mut C) -> Option<Duration>
+    where C: TimeSource + ?Sized,
+  {
+    self.age_at(millis_system_time(clock.now_millis())?)
+  }
+
+  /// Returns how long after the timestamp portion of `earlier` the timestamp portion of this
+  /// [`Ulid`] was.
+  ///
+  /// This returns `None` if `earlier` is actually later than this [`Ulid`], or if either timestamp
+  /// is out of bounds.
+  pub fn elapsed_since(&self, earlier: &Ulid) -> Option<Duration> {
+    self.as_system_time_opt()?.duration_since(earlier.as_system_time_opt()?).ok()
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `ttl` in the past.
+  ///
+  /// [`Ulid`]s with a timestamp in the future or out of bounds are never older than `ttl`. To check
+  /// at a point in time other than now, use [`Ulid::is_older_than_at()`].
+  #[inline]
+  pub fn is_older_than(&self, ttl: Duration) -> bool {
+    self.is_older_than_at(ttl, SystemTime::now())
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `ttl` before `now`.
+  ///
+  /// [`Ulid`]s with a timestamp after `now` or out of bounds are never older than `ttl`.
+  pub fn is_older_than_at(&self, ttl: Duration, now: SystemTime) -> bool {
+    self.age_at(now).is_some_and(|age| age > ttl)
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `ttl` before the current
+  /// time of `clock`.
+  ///
+  /// [`Ulid`]s with a timestamp after the current time or out of bounds are never older than `ttl`.
+  pub fn is_older_than_with<C>(&self, ttl: Duration, clock: &mut C) -> bool
+    where C: TimeSource + ?Sized,
+  {
+    self.age_with(clock).is_some_and(|age| age > ttl)
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `tolerance` in the
+  /// future.
+  ///
+  /// The tolerance allows for clock skew between the machine that created the [`Ulid`] and this
+  /// one. [`Ulid`]s with a timestamp out of bounds are never in the future. To check at a point in
+  /// time other than now, use [`Ulid::is_in_future_at()`].
+  #[inline]
+  pub fn is_in_future(&self, tolerance: Duration) -> bool {
+    self.is_in_future_at(tolerance, SystemTime::now())
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `tolerance` after `now`.
+  ///
+  /// [`Ulid`]s with a timestamp out of bounds are never in the future.
+  pub fn is_in_future_at(&self, tolerance: Duration, now: SystemTime) -> bool {
+    self.as_system_time_opt()
+      .and_then(|timestamp| timestamp.duration_since(now).ok())
+      .is_some_and(|ahead| ahead > tolerance)
+  }
+
+  /// Returns `true` if the timestamp portion of this [`Ulid`] is more than `tolerance` after the
+  /// current time of `clock`.
+  ///
+  /// [`Ulid`]s with a timestamp out of bounds are never in the future.
+  pub fn is_in_future_with<C>(&self, tolerance: Duration, clock: &mut C) -> bool
+    where C: TimeSource + ?Sized,
+  {
+    millis_system_time(clock.now_millis())
+      .is_some_and(|now| self.is_in_future_at(tolerance, now))
   }
 }
@@ -2,12 +2,28 @@ use crate::{
   Ulid,
   BytesError, ParseError,
   adapter::{
+    Descending, DescendingRef,
     Lowercase, LowercaseRef,
     Uppercase, UppercaseRef,
   },
 };
 
-use core::fmt;
+use core::{error::Error, fmt, str::FromStr};
+
+impl FromStr for Ulid {
+  type Err = ParseError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ulid::parse_str(s)
+  }
+}
+
+impl fmt::Debug for Ulid {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
 
 impl fmt::Display for Ulid {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -15,6 +31,20 @@ impl fmt::Display for Ulid {
   }
 }
 
+impl fmt::Display for Descending {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.encode())
+  }
+}
+
+impl<'a> fmt::Display for DescendingRef<'a> {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.encode())
+  }
+}
+
 impl fmt::Display for Lowercase {
   #[inline]
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -73,3 +103,22 @@ impl fmt::Display for BytesError {
     )
   }
 }
+
+#[cfg(feature = "uuid")]
+impl fmt::Display for crate::UuidError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "expected a version 7 UUID, found version {} with the {:?} variant",
+      self.found().get_version_num(),
+      self.found().get_variant(),
+    )
+  }
+}
+
+impl Error for BytesError {}
+
+impl Error for ParseError {}
+
+#[cfg(feature = "uuid")]
+impl Error for crate::UuidError {}
@@ -0,0 +1,51 @@
+//! [`defmt`] implementations for [`Ulid`], the [`adapter`](crate::adapter) types and the errors.
+//!
+//! Each is logged as the same string as its [`Display`](core::fmt::Display) implementation.
+
+use crate::{
+  BytesError,
+  ParseError,
+  Ulid,
+  adapter::{Descending, DescendingRef, Lowercase, LowercaseRef, Uppercase, UppercaseRef},
+};
+
+use defmt::{Format, Formatter};
+
+impl Format for Ulid {
+  fn format(&self, f: Formatter<'_>) {
+    self.to_lowercase().format(f)
+  }
+}
+
+macro_rules! format_adapter {
+  ($($ty:ty),*) => {
+    $(
+      impl Format for $ty {
+        fn format(&self, f: Formatter<'_>) {
+          defmt::write!(f, "{=str}", &*self.encode())
+        }
+      }
+    )*
+  };
+}
+
+format_adapter!(Lowercase, LowercaseRef<'_>, Uppercase, UppercaseRef<'_>, Descending, DescendingRef<'_>);
+
+impl Format for BytesError {
+  fn format(&self, f: Formatter<'_>) {
+    defmt::write!(f, "invalid bytes length: expected {=usize}, found {=usize}", self.expected(), self.found())
+  }
+}
+
+impl Format for ParseError {
+  fn format(&self, f: Formatter<'_>) {
+    match *self {
+      ParseError::InvalidCharacter { found, index } => {
+        defmt::write!(f, "invalid character: expected valid base32, found {=char} at index {=usize}", found, index)
+      },
+      ParseError::InvalidLength { found } => {
+        defmt::write!(f, "invalid length: expected 26, found {=usize}", found)
+      },
+    }
+  }
+}
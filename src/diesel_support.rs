@@ -0,0 +1,100 @@
+//! [`diesel`] implementations for [`Ulid`].
+//!
+//! A [`Ulid`] can be bound to and loaded from three SQL types:
+//!
+//! - [`Binary`] on every backend, storing the 16 bytes as-is. Like the bytes themselves, a `BLOB`,
+//!   `BINARY(16)` or `BYTEA` column sorts in the same order as [`Ulid`]'s [`Ord`] implementation.
+//! - [`Text`], storing the 26-character lowercase string. Writing `Text` requires one of the
+//!   `diesel-sqlite`, `diesel-postgres` or `diesel-mysql` features for the backend in use.
+//! - [`Uuid`](diesel::sql_types::Uuid) on Postgres (with the `diesel-postgres` feature), storing
+//!   the 16 bytes in a native `UUID` column.
+
+use crate::Ulid;
+
+use diesel::{
+  backend::Backend,
+  deserialize::{self, FromSql},
+  serialize::{self, ToSql, Output},
+  sql_types::{Binary, Text},
+};
+
+impl<DB> ToSql<Binary, DB> for Ulid
+  where DB: Backend,
+        [u8]: ToSql<Binary, DB>,
+{
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+    <[u8] as ToSql<Binary, DB>>::to_sql(self.as_bytes(), out)
+  }
+}
+
+impl<DB> FromSql<Binary, DB> for Ulid
+  where DB: Backend,
+        Vec<u8>: FromSql<Binary, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    let bytes = <Vec<u8> as FromSql<Binary, DB>>::from_sql(bytes)?;
+    Ok(Ulid::from_slice(&bytes)?)
+  }
+}
+
+impl<DB> FromSql<Text, DB> for Ulid
+  where DB: Backend,
+        String: FromSql<Text, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    let string = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+    Ok(Ulid::parse_str(&string)?)
+  }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Text, diesel::sqlite::Sqlite> for Ulid {
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+    out.set_value(self.to_string());
+    Ok(serialize::IsNull::No)
+  }
+}
+
+#[cfg(feature = "diesel-mysql")]
+impl ToSql<Text, diesel::mysql::Mysql> for Ulid {
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::mysql::Mysql>) -> serialize::Result {
+    use std::io::Write;
+
+    write!(out, "{}", self)?;
+    Ok(serialize::IsNull::No)
+  }
+}
+
+#[cfg(feature = "diesel-postgres")]
+mod pg {
+  use crate::Ulid;
+
+  use diesel::{
+    deserialize::{self, FromSql},
+    pg::{Pg, PgValue},
+    serialize::{self, ToSql, Output},
+    sql_types::{Text, Uuid},
+  };
+
+  use std::io::Write;
+
+  impl ToSql<Text, Pg> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      write!(out, "{}", self)?;
+      Ok(serialize::IsNull::No)
+    }
+  }
+
+  impl ToSql<Uuid, Pg> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      out.write_all(self.as_bytes())?;
+      Ok(serialize::IsNull::No)
+    }
+  }
+
+  impl FromSql<Uuid, Pg> for Ulid {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+      Ok(Ulid::from_slice(value.as_bytes())?)
+    }
+  }
+}
@@ -1,16 +1,20 @@
 //! Implementations for [`Ulid`] generation.
 //!
-//! Only available with the `std` feature.
+//! Only available with the `std` and `rand` features.
 
-use crate::Ulid;
+use crate::{components::system_time_millis, Ulid};
 
-use byteorder::{BigEndian, ByteOrder};
-use chrono::{DateTime, Utc};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone};
 use rand::{
   distributions::{Distribution, Standard},
   Rng,
   thread_rng,
 };
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+use std::time::SystemTime;
 
 impl Ulid {
   /// Creates a random [`Ulid`] with the current timestamp.
@@ -26,19 +30,34 @@ impl Ulid {
   /// Creates a random [`Ulid`] with the current timestamp, using a custom source of randomness.
   pub fn from_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
     // get the timestamp portion of the ulid
-    let millis = Utc::now().timestamp_millis();
+    let millis = system_time_millis(SystemTime::now());
 
-    // create the buffer holding the raw bytes
-    let mut buf = [0; 16];
+    // fill the random portion of the ulid
+    let mut random = [0; 10];
+    rng.fill(&mut random);
 
-    // write the timestamp section into the buffer
-    BigEndian::write_i48(&mut buf, millis);
+    // construct the resulting ulid
+    Ulid::from_millis_bytes(millis, random)
+  }
 
-    // fill the rest of the buffer with random bytes
-    rng.fill(&mut buf[6..]);
+  /// Creates a [`Ulid`] from a [`SystemTime`].
+  ///
+  /// This function will use the provided time for the timestamp portion of the [`Ulid`], and the
+  /// [`rand`] crate's default task RNG will be used for the random portion.
+  ///
+  /// To use a custom source of randomness with a time, see [`Ulid::from_system_time_with_rng()`].
+  #[inline]
+  pub fn from_system_time(time: SystemTime) -> Self {
+    Ulid::from_system_time_with_rng(time, &mut thread_rng())
+  }
 
-    // construct the resulting ulid
-    Ulid(buf)
+  /// Creates a [`Ulid`] from a [`SystemTime`] and a custom RNG.
+  ///
+  /// This function will use the provided time for the timestamp portion of the [`Ulid`], and the
+  /// provided [`Rng`] will be used for the random portion.
+  #[inline]
+  pub fn from_system_time_with_rng<R: Rng + ?Sized>(time: SystemTime, rng: &mut R) -> Self {
+    Ulid::from_millis_with_rng(system_time_millis(time), rng)
   }
 
   /// Creates a [`Ulid`] from a timestamp.
@@ -48,8 +67,11 @@ impl Ulid {
   ///
   /// To use a custom source of randomness with a timestamp, see
   /// [`Ulid::from_timestamp_with_rng()`].
+  ///
+  /// Only available with the `chrono` feature.
+  #[cfg(feature = "chrono")]
   #[inline]
-  pub fn from_timestamp(timestamp: DateTime<Utc>) -> Self {
+  pub fn from_timestamp<Tz: TimeZone>(timestamp: DateTime<Tz>) -> Self {
     Ulid::from_timestamp_with_rng(timestamp, &mut thread_rng())
   }
 
@@ -57,15 +79,42 @@ impl Ulid {
   ///
   /// This function will use the provided timestamp for the timestamp portion of the [`Ulid`], and
   /// the provided [`Rng`] will be used for the random portion.
+  ///
+  /// Only available with the `chrono` feature.
+  #[cfg(feature = "chrono")]
   #[inline]
-  pub fn from_timestamp_with_rng<R: Rng + ?Sized>(timestamp: DateTime<Utc>, rng: &mut R) -> Self {
+  pub fn from_timestamp_with_rng<Tz, R>(timestamp: DateTime<Tz>, rng: &mut R) -> Self
+    where Tz: TimeZone,
+          R: Rng + ?Sized,
+  {
     Ulid::from_millis_with_rng(timestamp.timestamp_millis(), rng)
   }
 
-  /// Creates a [`Ulid`] from a timestamp and the provided bytes.
+  /// Creates a [`Ulid`] from an [`OffsetDateTime`].
+  ///
+  /// This function will use the provided timestamp for the timestamp portion of the [`Ulid`], and
+  /// the [`rand`] crate's default task RNG will be used for the random portion.
+  ///
+  /// To use a custom source of randomness with a timestamp, see
+  /// [`Ulid::from_offset_date_time_with_rng()`].
+  ///
+  /// Only available with the `time` feature.
+  #[cfg(feature = "time")]
+  #[inline]
+  pub fn from_offset_date_time(timestamp: OffsetDateTime) -> Self {
+    Ulid::from_offset_date_time_with_rng(timestamp, &mut thread_rng())
+  }
+
+  /// Creates a [`Ulid`] from an [`OffsetDateTime`] and a custom RNG.
+  ///
+  /// This function will use the provided timestamp for the timestamp portion of the [`Ulid`], and
+  /// the provided [`Rng`] will be used for the random portion.
+  ///
+  /// Only available with the `time` feature.
+  #[cfg(feature = "time")]
   #[inline]
-  pub fn from_timestamp_bytes(timestamp: DateTime<Utc>, bytes: [u8; 10]) -> Self {
-    Ulid::from_millis_bytes(timestamp.timestamp_millis(), bytes)
+  pub fn from_offset_date_time_with_rng<R: Rng + ?Sized>(timestamp: OffsetDateTime, rng: &mut R) -> Self {
+    Ulid::from_millis_with_rng(crate::time::offset_date_time_millis(timestamp), rng)
   }
 
   /// Creates a [`Ulid`] from milliseconds.
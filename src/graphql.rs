@@ -0,0 +1,29 @@
+//! [`async_graphql`] scalar implementation for [`Ulid`].
+//!
+//! [`Ulid`]s are exposed as a custom `ULID` scalar, using the canonical (lowercase) string form.
+
+use crate::Ulid;
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A universally unique lexicographically sortable identifier, as 26 base32 digits.
+#[Scalar(name = "ULID", specified_by_url = "https://github.com/ulid/spec")]
+impl ScalarType for Ulid {
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match value {
+      Value::String(s) => Ok(Ulid::parse_str(&s)?),
+      other => Err(InputValueError::expected_type(other)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::String(s) => Ulid::parse_str(s).is_ok(),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.to_string())
+  }
+}
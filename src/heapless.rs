@@ -0,0 +1,38 @@
+//! Conversions from [`Ulid`] and the [`adapter`](crate::adapter) types to [`heapless::String`].
+//!
+//! A ULID string is always 26 characters long, so it fits in a `heapless::String<26>` without
+//! allocating.
+
+use crate::{
+  Ulid,
+  adapter::{Descending, DescendingRef, Lowercase, LowercaseRef, Uppercase, UppercaseRef},
+  parser::Encoded,
+};
+
+use heapless::String;
+
+fn to_string(encoded: Encoded) -> String<26> {
+  let mut string = String::new();
+  string.push_str(&encoded).expect("a ULID string is 26 characters long");
+  string
+}
+
+impl From<Ulid> for String<26> {
+  fn from(ulid: Ulid) -> Self {
+    to_string(ulid.to_lowercase().encode())
+  }
+}
+
+macro_rules! from_adapter {
+  ($($ty:ty),*) => {
+    $(
+      impl From<$ty> for String<26> {
+        fn from(adapter: $ty) -> Self {
+          to_string(adapter.encode())
+        }
+      }
+    )*
+  };
+}
+
+from_adapter!(Lowercase, LowercaseRef<'_>, Uppercase, UppercaseRef<'_>, Descending, DescendingRef<'_>);
@@ -0,0 +1,146 @@
+//! Human-readable inspection of [`Ulid`]s.
+
+use crate::Ulid;
+
+use core::fmt;
+
+/// A breakdown of the components of a [`Ulid`], created by [`Ulid::inspect()`].
+///
+/// The [`Display`](fmt::Display) implementation prints a multi-line report, which is useful in logs
+/// and test failures.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UlidInfo {
+  ulid: Ulid,
+  string: String,
+  hex: String,
+  time: Option<String>,
+  valid: bool,
+}
+
+impl Ulid {
+  /// Returns a [`UlidInfo`] describing the components of this [`Ulid`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+  /// let info = ulid.inspect();
+  ///
+  /// assert_eq!("0167f5d69a0c6bc8e4c2663aec52f757", info.hex());
+  /// assert_eq!(1546017741324, info.millis());
+  /// assert!(info.is_valid());
+  /// ```
+  pub fn inspect(&self) -> UlidInfo {
+    UlidInfo {
+      ulid: *self,
+      string: self.to_lowercase_ref().to_string(),
+      hex: format!("{:032x}", self.as_u128()),
+      time: rfc3339(self),
+      valid: self.as_millis() >= 0 && self.as_system_time_opt().is_some(),
+    }
+  }
+}
+
+fn rfc3339(ulid: &Ulid) -> Option<String> {
+  ulid.as_system_time_opt()?;
+
+  let millis = ulid.as_millis();
+  let (year, month, day) = civil_from_days(millis.div_euclid(MILLIS_PER_DAY));
+  if !(0..=9999).contains(&year) {
+    return None;
+  }
+
+  let millis_of_day = millis.rem_euclid(MILLIS_PER_DAY);
+  Some(format!(
+    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+    year,
+    month,
+    day,
+    millis_of_day / 3_600_000,
+    millis_of_day / 60_000 % 60,
+    millis_of_day / 1000 % 60,
+    millis_of_day % 1000,
+  ))
+}
+
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// Converts days since the Unix epoch to a proleptic Gregorian `(year, month, day)`.
+///
+/// This is Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days.rem_euclid(146_097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let shifted_month = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+  let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+  (year, month, day)
+}
+
+impl UlidInfo {
+  /// The [`Ulid`] being described.
+  pub const fn ulid(&self) -> Ulid {
+    self.ulid
+  }
+
+  /// The canonical (lowercase) string form of the [`Ulid`].
+  pub fn string(&self) -> &str {
+    &self.string
+  }
+
+  /// The 32 lowercase hexadecimal digits of the [`Ulid`]'s bytes.
+  pub fn hex(&self) -> &str {
+    &self.hex
+  }
+
+  /// The [`u128`] value of the [`Ulid`].
+  pub fn as_u128(&self) -> u128 {
+    self.ulid.as_u128()
+  }
+
+  /// The milliseconds of the timestamp portion of the [`Ulid`].
+  pub fn millis(&self) -> i64 {
+    self.ulid.as_millis()
+  }
+
+  /// The timestamp portion of the [`Ulid`] formatted as RFC 3339.
+  ///
+  /// This is always formatted with exactly three fractional digits, and is `None` if the timestamp
+  /// is out of bounds or its year is outside `0000` to `9999`.
+  pub fn time(&self) -> Option<&str> {
+    self.time.as_deref()
+  }
+
+  /// The 80 bits of the random portion of the [`Ulid`].
+  pub fn random(&self) -> u128 {
+    self.ulid.as_u128() & ((1 << 80) - 1)
+  }
+
+  /// Whether the timestamp portion of the [`Ulid`] is a plausible creation time: not before the
+  /// Unix epoch, and representable as a [`SystemTime`](std::time::SystemTime).
+  ///
+  /// Timestamps with the most significant of their 48 bits set are read as before the epoch (see
+  /// [`Ulid::as_millis()`]), so they are not valid.
+  pub const fn is_valid(&self) -> bool {
+    self.valid
+  }
+}
+
+impl fmt::Display for UlidInfo {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(f, "ulid:    {}", self.string())?;
+    writeln!(f, "hex:     {}", self.hex())?;
+    writeln!(f, "u128:    {}", self.as_u128())?;
+    writeln!(f, "millis:  {}", self.millis())?;
+    writeln!(f, "time:    {}", self.time().unwrap_or("unavailable"))?;
+    writeln!(f, "random:  {:020x}", self.random())?;
+    write!(f, "valid:   {}", self.is_valid())
+  }
+}
@@ -0,0 +1,145 @@
+//! Extension traits for reading and writing [`Ulid`]s.
+//!
+//! `ReadUlidExt` and `WriteUlidExt` extend `std::io::Read` and `std::io::Write`, while
+//! `BufUlidExt` and `BufMutUlidExt` extend `bytes::Buf` and `bytes::BufMut` (with the `bytes`
+//! feature).
+//!
+//! A [`Ulid`] is normally sent as its 16 bytes, which is the same as a big-endian [`u128`], so the
+//! plain methods can also read and write peers that send the ULID as a big-endian 128-bit integer.
+//! The `_le` methods read and write it as a little-endian 128-bit integer instead, with the bytes
+//! in reverse order.
+
+use crate::Ulid;
+
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+/// Extends [`Read`] with methods for reading [`Ulid`]s.
+///
+/// # Examples
+///
+/// ```
+/// use yulid::{io::{ReadUlidExt, WriteUlidExt}, Ulid};
+///
+/// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+///
+/// let mut buf = Vec::new();
+/// buf.write_ulid(&ulid).unwrap();
+/// buf.write_ulid_le(&ulid).unwrap();
+///
+/// let mut reader = &buf[..];
+/// assert_eq!(ulid, reader.read_ulid().unwrap());
+/// assert_eq!(ulid, reader.read_ulid_le().unwrap());
+/// ```
+#[cfg(feature = "std")]
+pub trait ReadUlidExt: Read {
+  /// Reads a [`Ulid`] from its 16 bytes, or equivalently from a big-endian [`u128`].
+  ///
+  /// # Errors
+  ///
+  /// This method returns the same errors as [`Read::read_exact`].
+  fn read_ulid(&mut self) -> io::Result<Ulid> {
+    let mut bytes = [0; 16];
+    self.read_exact(&mut bytes)?;
+    Ok(Ulid::from_bytes(bytes))
+  }
+
+  /// Reads a [`Ulid`] from a little-endian [`u128`].
+  ///
+  /// # Errors
+  ///
+  /// This method returns the same errors as [`Read::read_exact`].
+  fn read_ulid_le(&mut self) -> io::Result<Ulid> {
+    let mut bytes = [0; 16];
+    self.read_exact(&mut bytes)?;
+    Ok(Ulid::from_u128(u128::from_le_bytes(bytes)))
+  }
+}
+
+#[cfg(feature = "std")]
+impl<R> ReadUlidExt for R
+  where R: Read + ?Sized,
+{}
+
+/// Extends [`Write`] with methods for writing [`Ulid`]s.
+#[cfg(feature = "std")]
+pub trait WriteUlidExt: Write {
+  /// Writes the 16 bytes of a [`Ulid`], or equivalently its big-endian [`u128`].
+  ///
+  /// # Errors
+  ///
+  /// This method returns the same errors as [`Write::write_all`].
+  fn write_ulid(&mut self, ulid: &Ulid) -> io::Result<()> {
+    self.write_all(ulid.as_bytes())
+  }
+
+  /// Writes a [`Ulid`] as a little-endian [`u128`].
+  ///
+  /// # Errors
+  ///
+  /// This method returns the same errors as [`Write::write_all`].
+  fn write_ulid_le(&mut self, ulid: &Ulid) -> io::Result<()> {
+    self.write_all(&ulid.as_u128().to_le_bytes())
+  }
+}
+
+#[cfg(feature = "std")]
+impl<W> WriteUlidExt for W
+  where W: Write + ?Sized,
+{}
+
+/// Extends [`bytes::Buf`] with methods for getting [`Ulid`]s.
+#[cfg(feature = "bytes")]
+pub trait BufUlidExt: bytes::Buf {
+  /// Gets a [`Ulid`] from its 16 bytes, or equivalently from a big-endian [`u128`].
+  ///
+  /// # Panics
+  ///
+  /// This method panics if there are fewer than 16 bytes remaining, like [`bytes::Buf::get_u128`].
+  fn get_ulid(&mut self) -> Ulid {
+    Ulid::from_u128(self.get_u128())
+  }
+
+  /// Gets a [`Ulid`] from a little-endian [`u128`].
+  ///
+  /// # Panics
+  ///
+  /// This method panics if there are fewer than 16 bytes remaining, like
+  /// [`bytes::Buf::get_u128_le`].
+  fn get_ulid_le(&mut self) -> Ulid {
+    Ulid::from_u128(self.get_u128_le())
+  }
+}
+
+#[cfg(feature = "bytes")]
+impl<B> BufUlidExt for B
+  where B: bytes::Buf + ?Sized,
+{}
+
+/// Extends [`bytes::BufMut`] with methods for putting [`Ulid`]s.
+#[cfg(feature = "bytes")]
+pub trait BufMutUlidExt: bytes::BufMut {
+  /// Puts the 16 bytes of a [`Ulid`], or equivalently its big-endian [`u128`].
+  ///
+  /// # Panics
+  ///
+  /// This method panics if there is not enough capacity left, like [`bytes::BufMut::put_u128`].
+  fn put_ulid(&mut self, ulid: &Ulid) {
+    self.put_slice(ulid.as_bytes())
+  }
+
+  /// Puts a [`Ulid`] as a little-endian [`u128`].
+  ///
+  /// # Panics
+  ///
+  /// This method panics if there is not enough capacity left, like
+  /// [`bytes::BufMut::put_u128_le`].
+  fn put_ulid_le(&mut self, ulid: &Ulid) {
+    self.put_u128_le(ulid.as_u128())
+  }
+}
+
+#[cfg(feature = "bytes")]
+impl<B> BufMutUlidExt for B
+  where B: bytes::BufMut + ?Sized,
+{}
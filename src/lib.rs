@@ -11,42 +11,96 @@
 //! The uniqueness property is not strictly guaranteed, however for all practical purposes, it can
 //! be assumed that an unintentional collision would be extremely unlikely.
 
-#![cfg_attr(test, feature(test))]
-#![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(not(feature = "std"), feature(alloc))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 
-#[cfg(not(feature = "std"))]
-extern crate alloc;
-
-use byteorder::{BigEndian, ByteOrder};
-
 #[cfg(test)]
 mod test;
 
 pub mod prelude;
 pub mod parser;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "rand")]
 pub mod generation;
 #[cfg(feature = "std")]
 pub mod components;
+pub mod clock;
+#[cfg(feature = "rand_core")]
+mod rand_core;
 pub mod adapter;
+mod bucket;
 mod core_support;
 #[cfg(feature = "std")]
-mod std_support;
+pub mod inspect;
+#[cfg(any(feature = "std", feature = "bytes"))]
+pub mod io;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
 #[cfg(feature = "uuid")]
 mod uuid;
+#[cfg(feature = "ulid-interop")]
+mod ulid_interop;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "diesel")]
+mod diesel_support;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "redis")]
+mod redis;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+#[cfg(feature = "prost")]
+pub mod proto;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "async-graphql")]
+mod graphql;
 
 pub use self::parser::ParseError;
+#[cfg(feature = "std")]
+pub use self::inspect::UlidInfo;
+#[cfg(feature = "uuid")]
+pub use self::uuid::UuidError;
 
 /// A 128-bit (16 byte) buffer containing the ID.
 pub type Bytes = [u8; 16];
 
 /// A universally unique lexicographically sortable identifier (ULID).
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+  feature = "diesel",
+  derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+  diesel(sql_type = diesel::sql_types::Binary),
+  diesel(sql_type = diesel::sql_types::Text),
+)]
+#[cfg_attr(
+  feature = "diesel-postgres",
+  diesel(sql_type = diesel::sql_types::Uuid),
+)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(
+    zerocopy::FromBytes,
+    zerocopy::Immutable,
+    zerocopy::IntoBytes,
+    zerocopy::KnownLayout,
+    zerocopy::Unaligned,
+  ),
+)]
+#[repr(transparent)]
 pub struct Ulid(Bytes);
 
 impl Ulid {
@@ -82,6 +136,26 @@ impl Ulid {
     Ulid(bytes)
   }
 
+  /// Reinterprets a reference to 16 bytes as a reference to a [`Ulid`], without copying.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```
+  /// use yulid::{Bytes, Ulid};
+  ///
+  /// let bytes: Bytes = [1, 103, 245, 214, 154, 12, 107, 200, 228, 194, 102, 58, 236, 82, 247, 87];
+  ///
+  /// let ulid: &Ulid = Ulid::from_bytes_ref(&bytes);
+  ///
+  /// assert_eq!(ulid.as_bytes(), &bytes);
+  /// ```
+  pub const fn from_bytes_ref(bytes: &Bytes) -> &Self {
+    // SAFETY: `Ulid` is `repr(transparent)` over `Bytes`, so the two have the same layout.
+    unsafe { &*(bytes as *const Bytes as *const Ulid) }
+  }
+
   /// Creates a [`Ulid`] using the supplied bytes.
   ///
   /// # Errors
@@ -129,20 +203,73 @@ impl Ulid {
     Ok(Ulid::from_bytes(bytes))
   }
 
+  /// Reinterprets a slice of packed 16-byte ULIDs as a slice of [`Ulid`]s, without copying.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the length of `slice` is not a multiple of 16. The
+  /// error's expected length is the length the slice would need to hold one more whole [`Ulid`].
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let bytes = [0; 48];
+  ///
+  /// let ulids = Ulid::slice_from_bytes(&bytes).unwrap();
+  ///
+  /// assert_eq!(ulids, &[Ulid::default(); 3]);
+  /// ```
+  ///
+  /// An incorrect number of bytes:
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let bytes = [0; 40];
+  ///
+  /// let ulids = Ulid::slice_from_bytes(&bytes);
+  ///
+  /// assert_eq!(ulids, Err(yulid::BytesError::new(48, 40)));
+  /// ```
+  pub fn slice_from_bytes(slice: &[u8]) -> Result<&[Self], BytesError> {
+    let len = slice.len();
+    let remainder = len % 16;
+    if remainder != 0 {
+      return Err(BytesError::new(len - remainder + 16, len));
+    }
+
+    // SAFETY: `Ulid` is `repr(transparent)` over `Bytes`, which has an alignment of 1 and no
+    // invalid bit patterns, and `slice` holds exactly `len / 16` of them.
+    Ok(unsafe { core::slice::from_raw_parts(slice.as_ptr() as *const Ulid, len / 16) })
+  }
+
   /// Creates a [`Ulid`] from milliseconds and the provided bytes.
-  pub fn from_millis_bytes(millis: i64, mut bytes: [u8; 10]) -> Self {
+  ///
+  /// Only the low 48 bits of `millis` are used.
+  pub const fn from_millis_bytes(millis: i64, bytes: [u8; 10]) -> Self {
     let mut buf = [0; 16];
-    BigEndian::write_i48(&mut buf, millis);
 
-    buf[6..].swap_with_slice(&mut bytes);
+    let mut i = 0;
+    while i < 6 {
+      buf[i] = (millis >> (40 - i * 8)) as u8;
+      i += 1;
+    }
+    while i < 16 {
+      buf[i] = bytes[i - 6];
+      i += 1;
+    }
 
     Ulid::from_bytes(buf)
   }
 
   /// Creates a [`Ulid`] from a [`u128`] value.
   #[inline]
-  pub fn from_u128(int: u128) -> Self {
-    Ulid::from(int)
+  pub const fn from_u128(int: u128) -> Self {
+    Ulid::from_bytes(int.to_be_bytes())
   }
 
   /// Creates a [`Ulid`] from five field values.
@@ -163,7 +290,7 @@ impl Ulid {
   ///
   /// assert_eq!(expected_ulid, ulid);
   /// ```
-  pub fn from_fields(f1: u32, f2: u16, f3: u16, f4: u32, f5: u32) -> Self {
+  pub const fn from_fields(f1: u32, f2: u16, f3: u16, f4: u32, f5: u32) -> Self {
     Ulid::from_bytes([
       (f1 >> 24) as u8,
       (f1 >> 16) as u8,
@@ -212,35 +339,105 @@ impl Ulid {
   ///   ),
   /// );
   /// ```
-  pub fn as_fields(&self) -> (u32, u16, u16, u32, u32) {
-    let f1 = u32::from(self.as_bytes()[0]) << 24
-      | u32::from(self.as_bytes()[1]) << 16
-      | u32::from(self.as_bytes()[2]) << 8
-      | u32::from(self.as_bytes()[3]);
+  pub const fn as_fields(&self) -> (u32, u16, u16, u32, u32) {
+    let bytes = self.as_bytes();
 
-    let f2 = u16::from(self.as_bytes()[4]) << 8
-      | u16::from(self.as_bytes()[5]);
+    let f1 = (bytes[0] as u32) << 24
+      | (bytes[1] as u32) << 16
+      | (bytes[2] as u32) << 8
+      | bytes[3] as u32;
 
-    let f3 = u16::from(self.as_bytes()[6]) << 8
-      | u16::from(self.as_bytes()[7]);
+    let f2 = (bytes[4] as u16) << 8
+      | bytes[5] as u16;
 
-    let f4 = u32::from(self.as_bytes()[8]) << 24
-      | u32::from(self.as_bytes()[9]) << 16
-      | u32::from(self.as_bytes()[10]) << 8
-      | u32::from(self.as_bytes()[11]);
+    let f3 = (bytes[6] as u16) << 8
+      | bytes[7] as u16;
 
-    let f5 = u32::from(self.as_bytes()[12]) << 24
-      | u32::from(self.as_bytes()[13]) << 16
-      | u32::from(self.as_bytes()[14]) << 8
-      | u32::from(self.as_bytes()[15]);
+    let f4 = (bytes[8] as u32) << 24
+      | (bytes[9] as u32) << 16
+      | (bytes[10] as u32) << 8
+      | bytes[11] as u32;
+
+    let f5 = (bytes[12] as u32) << 24
+      | (bytes[13] as u32) << 16
+      | (bytes[14] as u32) << 8
+      | bytes[15] as u32;
 
     (f1, f2, f3, f4, f5)
   }
 
   /// Returns the [`u128`] value represented by this [`Ulid`].
   #[inline]
-  pub fn as_u128(self) -> u128 {
-    self.into()
+  pub const fn as_u128(self) -> u128 {
+    u128::from_be_bytes(self.0)
+  }
+
+  /// Returns the high and low 64 bits of this [`Ulid`], for stores without 128-bit integers.
+  ///
+  /// Comparing the pairs lexicographically (high first) gives the same order as comparing the
+  /// [`Ulid`]s. Use [`Ulid::from_u64_pair()`] to get the original [`Ulid`] back.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+  ///
+  /// assert_eq!(ulid.to_u64_pair(), (0x0167_F5D6_9A0C_6BC8, 0xE4C2_663A_EC52_F757));
+  /// ```
+  pub const fn to_u64_pair(&self) -> (u64, u64) {
+    let int = self.as_u128();
+    ((int >> 64) as u64, int as u64)
+  }
+
+  /// Creates a [`Ulid`] from the high and low 64 bits returned by [`Ulid::to_u64_pair()`].
+  pub const fn from_u64_pair(hi: u64, lo: u64) -> Self {
+    Ulid::from_u128((hi as u128) << 64 | lo as u128)
+  }
+
+  /// Returns the high and low 64 bits of this [`Ulid`] as signed integers, for stores that only
+  /// have signed 64-bit columns.
+  ///
+  /// The sign bit of each half is flipped, so comparing the pairs lexicographically as signed
+  /// integers gives the same order as comparing the [`Ulid`]s: the smallest [`Ulid`] maps to
+  /// `(i64::MIN, i64::MIN)` and the largest to `(i64::MAX, i64::MAX)`. Use
+  /// [`Ulid::from_i64_pair()`] to get the original [`Ulid`] back.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let older = Ulid::from_u128(u128::from(u64::MAX));
+  /// let newer = Ulid::from_u128(1 << 127);
+  ///
+  /// assert!(older < newer);
+  /// assert!(older.to_i64_pair() < newer.to_i64_pair());
+  /// assert_eq!(Ulid::from_u128(0).to_i64_pair(), (i64::MIN, i64::MIN));
+  /// ```
+  pub const fn to_i64_pair(&self) -> (i64, i64) {
+    let (hi, lo) = self.to_u64_pair();
+    ((hi ^ 1 << 63) as i64, (lo ^ 1 << 63) as i64)
+  }
+
+  /// Creates a [`Ulid`] from the signed pair returned by [`Ulid::to_i64_pair()`].
+  pub const fn from_i64_pair(hi: i64, lo: i64) -> Self {
+    Ulid::from_u64_pair(hi as u64 ^ 1 << 63, lo as u64 ^ 1 << 63)
+  }
+
+  /// Returns this [`Ulid`] as a signed 128-bit integer.
+  ///
+  /// The sign bit is flipped, so comparing the integers gives the same order as comparing the
+  /// [`Ulid`]s: the smallest [`Ulid`] maps to [`i128::MIN`] and the largest to [`i128::MAX`]. Use
+  /// [`Ulid::from_i128()`] to get the original [`Ulid`] back.
+  pub const fn to_i128(&self) -> i128 {
+    (self.as_u128() ^ 1 << 127) as i128
+  }
+
+  /// Creates a [`Ulid`] from the signed integer returned by [`Ulid::to_i128()`].
+  pub const fn from_i128(int: i128) -> Self {
+    Ulid::from_u128(int as u128 ^ 1 << 127)
   }
 
   /// Returns an array of 16 octets containing the [`Ulid`] data.
@@ -248,9 +445,45 @@ impl Ulid {
     &self.0
   }
 
+  /// Returns the descending sort key of this [`Ulid`].
+  ///
+  /// The key is the bitwise complement of the [`Ulid`]'s bytes, so sorting keys in ascending order
+  /// yields the [`Ulid`]s newest-first. This is useful for stores that can only scan forward. Use
+  /// [`Ulid::from_descending_key()`] to get the original [`Ulid`] back.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use yulid::Ulid;
+  ///
+  /// let older = Ulid::from_u128(1);
+  /// let newer = Ulid::from_u128(2);
+  ///
+  /// assert!(older < newer);
+  /// assert!(older.to_descending_key() > newer.to_descending_key());
+  /// ```
+  pub const fn to_descending_key(&self) -> Bytes {
+    Ulid::from_u128(!self.as_u128()).0
+  }
+
+  /// Creates a [`Ulid`] from a descending sort key created by [`Ulid::to_descending_key()`].
+  pub const fn from_descending_key(key: Bytes) -> Self {
+    Ulid::from_u128(!Ulid::from_bytes(key).as_u128())
+  }
+
   /// Returns the milliseconds of the timestamp portion of the [`Ulid`].
-  pub fn as_millis(&self) -> i64 {
-    BigEndian::read_i48(self.as_bytes())
+  pub const fn as_millis(&self) -> i64 {
+    let bytes = self.as_bytes();
+
+    let mut millis = 0;
+    let mut i = 0;
+    while i < 6 {
+      millis = millis << 8 | bytes[i] as i64;
+      i += 1;
+    }
+
+    // sign-extend the 48-bit value
+    millis << 16 >> 16
   }
 
   /// Parses a [`Ulid`] from a string of case-insensitive base32 digits.
@@ -263,9 +496,7 @@ impl Ulid {
         found: input.len(),
       })
     };
-    let bytes = crate::parser::decode(input)?;
-    // we know the slice is valid length
-    Ok(Ulid::from_slice(&bytes).unwrap())
+    crate::parser::decode(input).map(Ulid::from_bytes)
   }
 }
 
@@ -295,15 +526,12 @@ impl BytesError {
 
 impl From<u128> for Ulid {
   fn from(u: u128) -> Self {
-    let mut bytes = [0; 16];
-    BigEndian::write_u128(&mut bytes, u);
-
-    Ulid::from_bytes(bytes)
+    Ulid::from_u128(u)
   }
 }
 
 impl From<Ulid> for u128 {
   fn from(u: Ulid) -> Self {
-    BigEndian::read_u128(u.as_bytes())
+    u.as_u128()
   }
 }
@@ -0,0 +1,51 @@
+//! [`postgres_types`] implementations for [`Ulid`].
+//!
+//! A [`Ulid`] is written to and read from `UUID` and `BYTEA` columns as its 16 bytes, so no
+//! conversion through `uuid::Uuid` is needed to store it in a `UUID` column. `TEXT`, `VARCHAR` and
+//! `BPCHAR` columns hold the 26-character lowercase string.
+
+use crate::Ulid;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use std::{error::Error, fmt::Write};
+
+fn is_binary(ty: &Type) -> bool {
+  matches!(*ty, Type::UUID | Type::BYTEA)
+}
+
+fn is_text(ty: &Type) -> bool {
+  matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR)
+}
+
+impl ToSql for Ulid {
+  fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    if is_binary(ty) {
+      out.put_slice(self.as_bytes());
+    } else {
+      write!(out, "{}", self)?;
+    }
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool {
+    is_binary(ty) || is_text(ty)
+  }
+
+  to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Ulid {
+  fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+    if is_binary(ty) {
+      Ok(Ulid::from_slice(raw)?)
+    } else {
+      Ok(Ulid::parse_str(<&str as FromSql>::from_sql(ty, raw)?)?)
+    }
+  }
+
+  fn accepts(ty: &Type) -> bool {
+    is_binary(ty) || is_text(ty)
+  }
+}
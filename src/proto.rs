@@ -0,0 +1,66 @@
+//! Protocol Buffers support through [`prost`].
+//!
+//! [`Ulid`] is the canonical `yulid.v1.Ulid` message, defined in `proto/yulid/v1/ulid.proto` for
+//! use from other languages. It stores the 128 bits as two `fixed64` halves, which always encode to
+//! 18 bytes (or fewer when a half is zero) and can't hold an invalid value, so converting it to a
+//! [`crate::Ulid`] never fails.
+//!
+//! Messages that already carry a ULID in a `bytes` field can convert the [`Bytes`] directly with
+//! `crate::Ulid::try_from`, which returns a [`BytesError`] unless there are exactly 16 bytes.
+
+use crate::BytesError;
+
+use prost::bytes::Bytes;
+
+use core::convert::TryFrom;
+
+/// The `yulid.v1.Ulid` message.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, prost::Message)]
+pub struct Ulid {
+  /// The most significant 64 bits: the 48-bit timestamp and the first 16 random bits.
+  #[prost(fixed64, tag = "1")]
+  pub hi: u64,
+  /// The least significant 64 bits, all random.
+  #[prost(fixed64, tag = "2")]
+  pub lo: u64,
+}
+
+impl prost::Name for Ulid {
+  const NAME: &'static str = "Ulid";
+  const PACKAGE: &'static str = "yulid.v1";
+}
+
+impl From<crate::Ulid> for Ulid {
+  fn from(ulid: crate::Ulid) -> Self {
+    let (hi, lo) = ulid.to_u64_pair();
+    Ulid { hi, lo }
+  }
+}
+
+impl From<Ulid> for crate::Ulid {
+  fn from(ulid: Ulid) -> Self {
+    crate::Ulid::from_u64_pair(ulid.hi, ulid.lo)
+  }
+}
+
+impl TryFrom<Bytes> for crate::Ulid {
+  type Error = BytesError;
+
+  fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+    crate::Ulid::from_slice(&bytes)
+  }
+}
+
+impl<'a> TryFrom<&'a Bytes> for crate::Ulid {
+  type Error = BytesError;
+
+  fn try_from(bytes: &'a Bytes) -> Result<Self, Self::Error> {
+    crate::Ulid::from_slice(bytes)
+  }
+}
+
+impl From<crate::Ulid> for Bytes {
+  fn from(ulid: crate::Ulid) -> Self {
+    Bytes::copy_from_slice(ulid.as_bytes())
+  }
+}
@@ -0,0 +1,69 @@
+//! [`Ulid`] generation with a [`rand_core`] RNG and a [`TimeSource`].
+//!
+//! Unlike the [`generation`](crate::generation) functions, these need neither `std` nor the heap,
+//! so they can use a hardware RNG and an RTC on embedded targets.
+
+use crate::{clock::TimeSource, Ulid};
+
+use rand_core::RngCore;
+
+impl Ulid {
+  /// Creates a random [`Ulid`] with the timestamp from `clock`, using `rng` for the random portion.
+  ///
+  /// # Panics
+  ///
+  /// This function panics if `rng` fails, like [`RngCore::fill_bytes`]. See
+  /// [`Ulid::try_generate()`] for a non-panicking version.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use rand_core::impls::fill_bytes_via_next;
+  /// use yulid::Ulid;
+  ///
+  /// # struct Rng(u64);
+  /// # impl rand_core::RngCore for Rng {
+  /// #   fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+  /// #   fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+  /// #   fn fill_bytes(&mut self, dest: &mut [u8]) { fill_bytes_via_next(self, dest) }
+  /// #   fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+  /// #     self.fill_bytes(dest);
+  /// #     Ok(())
+  /// #   }
+  /// # }
+  /// let mut rng = Rng(0); // e.g. a hardware RNG
+  /// let mut clock = || 1_546_017_741_324; // e.g. an RTC
+  ///
+  /// let ulid = Ulid::generate(&mut clock, &mut rng);
+  ///
+  /// assert_eq!(ulid.as_millis(), 1_546_017_741_324);
+  /// ```
+  pub fn generate<C, R>(clock: &mut C, rng: &mut R) -> Self
+    where C: TimeSource + ?Sized,
+          R: RngCore + ?Sized,
+  {
+    let millis = clock.now_millis();
+
+    let mut random = [0; 10];
+    rng.fill_bytes(&mut random);
+
+    Ulid::from_millis_bytes(millis, random)
+  }
+
+  /// Creates a random [`Ulid`] with the timestamp from `clock`, using `rng` for the random portion.
+  ///
+  /// # Errors
+  ///
+  /// This function returns the error from [`RngCore::try_fill_bytes`] if `rng` fails.
+  pub fn try_generate<C, R>(clock: &mut C, rng: &mut R) -> Result<Self, rand_core::Error>
+    where C: TimeSource + ?Sized,
+          R: RngCore + ?Sized,
+  {
+    let millis = clock.now_millis();
+
+    let mut random = [0; 10];
+    rng.try_fill_bytes(&mut random)?;
+
+    Ok(Ulid::from_millis_bytes(millis, random))
+  }
+}
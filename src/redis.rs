@@ -0,0 +1,92 @@
+//! [`redis`] implementations for [`Ulid`] and the [`adapter`](crate::adapter) types.
+//!
+//! A [`Ulid`] is written as a 16-byte argument, which keeps keys and sorted-set members compact.
+//! Redis compares members with equal scores byte by byte, so they sort in the same order as
+//! [`Ulid`]'s [`Ord`] implementation. To write the canonical string instead, wrap it in one of the
+//! adapters, e.g. [`Ulid::to_lowercase`](crate::Ulid::to_lowercase).
+//!
+//! Reading a [`Ulid`] accepts both forms: a 16-byte bulk string is taken as the bytes, anything
+//! else is parsed as a string. The adapters only accept strings.
+
+use crate::{
+  Ulid,
+  adapter::{Descending, DescendingRef, Lowercase, LowercaseRef, Uppercase, UppercaseRef},
+};
+
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use std::fmt::Display;
+
+fn string_value(v: &Value) -> RedisResult<&str> {
+  match *v {
+    Value::BulkString(ref bytes) => std::str::from_utf8(bytes)
+      .map_err(|_| RedisError::from((ErrorKind::TypeError, "Response was not valid UTF-8"))),
+    Value::SimpleString(ref s) | Value::VerbatimString { text: ref s, .. } => Ok(s),
+    _ => Err(RedisError::from((
+      ErrorKind::TypeError,
+      "Response was of incompatible type",
+      format!("expected a ULID (response was {:?})", v),
+    ))),
+  }
+}
+
+fn invalid_ulid(e: impl Display) -> RedisError {
+  RedisError::from((ErrorKind::TypeError, "Response was not a valid ULID", e.to_string()))
+}
+
+impl ToRedisArgs for Ulid {
+  fn write_redis_args<W>(&self, out: &mut W)
+    where W: ?Sized + RedisWrite,
+  {
+    out.write_arg(self.as_bytes());
+  }
+}
+
+impl FromRedisValue for Ulid {
+  fn from_redis_value(v: &Value) -> RedisResult<Self> {
+    match *v {
+      Value::BulkString(ref bytes) if bytes.len() == 16 => Ulid::from_slice(bytes).map_err(invalid_ulid),
+      _ => Ulid::parse_str(string_value(v)?).map_err(invalid_ulid),
+    }
+  }
+}
+
+macro_rules! string_args {
+  ($($ty:ty),*) => {
+    $(
+      impl ToRedisArgs for $ty {
+        fn write_redis_args<W>(&self, out: &mut W)
+          where W: ?Sized + RedisWrite,
+        {
+          out.write_arg_fmt(self);
+        }
+      }
+    )*
+  };
+}
+
+string_args!(Lowercase, LowercaseRef<'_>, Uppercase, UppercaseRef<'_>, Descending, DescendingRef<'_>);
+
+impl FromRedisValue for Lowercase {
+  fn from_redis_value(v: &Value) -> RedisResult<Self> {
+    Ulid::parse_str(string_value(v)?)
+      .map(Lowercase::from_ulid)
+      .map_err(invalid_ulid)
+  }
+}
+
+impl FromRedisValue for Uppercase {
+  fn from_redis_value(v: &Value) -> RedisResult<Self> {
+    Ulid::parse_str(string_value(v)?)
+      .map(Uppercase::from_ulid)
+      .map_err(invalid_ulid)
+  }
+}
+
+impl FromRedisValue for Descending {
+  fn from_redis_value(v: &Value) -> RedisResult<Self> {
+    Ulid::parse_descending_str(string_value(v)?)
+      .map(Descending::from_ulid)
+      .map_err(invalid_ulid)
+  }
+}
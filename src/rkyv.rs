@@ -0,0 +1,34 @@
+//! [`rkyv`] implementations for [`Ulid`].
+//!
+//! A [`Ulid`] is archived as its 16 big-endian bytes, so the archived type is a plain `[u8; 16]`.
+//! Byte arrays compare lexicographically, so archived ULIDs sort in the same order as [`Ulid`]'s
+//! [`Ord`] implementation, and can be searched or compared without deserializing them.
+
+use crate::{Bytes, Ulid};
+
+use rkyv::{rancor::Fallible, Archive, Deserialize, Place, Serialize};
+
+impl Archive for Ulid {
+  type Archived = Bytes;
+  type Resolver = ();
+
+  fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+    out.write(*self.as_bytes());
+  }
+}
+
+impl<S> Serialize<S> for Ulid
+  where S: Fallible + ?Sized,
+{
+  fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+impl<D> Deserialize<Ulid, D> for Bytes
+  where D: Fallible + ?Sized,
+{
+  fn deserialize(&self, _: &mut D) -> Result<Ulid, D::Error> {
+    Ok(Ulid::from_bytes(*self))
+  }
+}
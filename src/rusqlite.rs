@@ -0,0 +1,69 @@
+//! [`rusqlite`] implementations for [`Ulid`] and the [`adapter`](crate::adapter) types.
+//!
+//! A [`Ulid`] is stored as a 16-byte `BLOB`. SQLite compares blobs byte by byte, so an index on the
+//! column sorts in the same order as [`Ulid`]'s [`Ord`] implementation.
+//!
+//! To store a [`Ulid`] as `TEXT` instead, wrap it in one of the adapters. [`Lowercase`] and
+//! [`Uppercase`] sort in the same order as the [`Ulid`] itself, while [`Descending`] sorts
+//! newest-first.
+
+use crate::{
+  Ulid,
+  adapter::{Descending, Lowercase, Uppercase},
+};
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Ulid {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.as_bytes())))
+  }
+}
+
+impl FromSql for Ulid {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    Ulid::from_slice(value.as_blob()?).map_err(|e| FromSqlError::Other(Box::new(e)))
+  }
+}
+
+impl ToSql for Lowercase {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.to_string()))
+  }
+}
+
+impl FromSql for Lowercase {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    Ulid::parse_str(value.as_str()?)
+      .map(Lowercase::from_ulid)
+      .map_err(|e| FromSqlError::Other(Box::new(e)))
+  }
+}
+
+impl ToSql for Uppercase {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.to_string()))
+  }
+}
+
+impl FromSql for Uppercase {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    Ulid::parse_str(value.as_str()?)
+      .map(Uppercase::from_ulid)
+      .map_err(|e| FromSqlError::Other(Box::new(e)))
+  }
+}
+
+impl ToSql for Descending {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.to_string()))
+  }
+}
+
+impl FromSql for Descending {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    Ulid::parse_descending_str(value.as_str()?)
+      .map(Descending::from_ulid)
+      .map_err(|e| FromSqlError::Other(Box::new(e)))
+  }
+}
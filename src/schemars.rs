@@ -0,0 +1,166 @@
+//! [`schemars`] implementations for [`Ulid`] and the [`adapter`](crate::adapter) types.
+//!
+//! The schemas describe the strings that the [`serde`](crate::serde) implementations produce in
+//! human-readable formats.
+
+use crate::{
+  Ulid,
+  adapter::{
+    Descending, DescendingRef,
+    Lowercase, LowercaseRef,
+    Uppercase, UppercaseRef,
+  },
+};
+
+use schemars::{
+  gen::SchemaGenerator,
+  schema::{InstanceType, Schema, SchemaObject, StringValidation},
+  JsonSchema,
+};
+
+use std::borrow::Cow;
+
+const PATTERN: &str = "^[0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{26}$";
+const PATTERN_LOWER: &str = "^[0-9a-hjkmnp-tv-z]{26}$";
+const PATTERN_UPPER: &str = "^[0-9A-HJKMNP-TV-Z]{26}$";
+
+/// Creates the schema of a 26-character string matching `pattern`.
+fn string_schema(pattern: &str, format: Option<&str>) -> Schema {
+  SchemaObject {
+    instance_type: Some(InstanceType::String.into()),
+    format: format.map(ToString::to_string),
+    string: Some(Box::new(StringValidation {
+      max_length: Some(26),
+      min_length: Some(26),
+      pattern: Some(pattern.to_string()),
+    })),
+    ..Default::default()
+  }.into()
+}
+
+impl JsonSchema for Ulid {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    "Ulid".to_string()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Cow::Borrowed("yulid::Ulid")
+  }
+
+  fn json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_schema(PATTERN, Some("ulid"))
+  }
+}
+
+impl JsonSchema for Lowercase {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    "LowercaseUlid".to_string()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Cow::Borrowed("yulid::adapter::Lowercase")
+  }
+
+  fn json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_schema(PATTERN_LOWER, Some("ulid"))
+  }
+}
+
+impl<'a> JsonSchema for LowercaseRef<'a> {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    Lowercase::schema_name()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Lowercase::schema_id()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    Lowercase::json_schema(gen)
+  }
+}
+
+impl JsonSchema for Uppercase {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    "UppercaseUlid".to_string()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Cow::Borrowed("yulid::adapter::Uppercase")
+  }
+
+  fn json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_schema(PATTERN_UPPER, Some("ulid"))
+  }
+}
+
+impl<'a> JsonSchema for UppercaseRef<'a> {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    Uppercase::schema_name()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Uppercase::schema_id()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    Uppercase::json_schema(gen)
+  }
+}
+
+impl JsonSchema for Descending {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    "DescendingUlid".to_string()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Cow::Borrowed("yulid::adapter::Descending")
+  }
+
+  fn json_schema(_: &mut SchemaGenerator) -> Schema {
+    // a descending key is not itself a ULID, so it has no format
+    string_schema(PATTERN_LOWER, None)
+  }
+}
+
+impl<'a> JsonSchema for DescendingRef<'a> {
+  fn is_referenceable() -> bool {
+    false
+  }
+
+  fn schema_name() -> String {
+    Descending::schema_name()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    Descending::schema_id()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    Descending::json_schema(gen)
+  }
+}
@@ -0,0 +1,107 @@
+//! [`serde`] implementations for the [`adapter`](crate::adapter) types.
+//!
+//! Adapters are always serialized as strings in their own case, whatever the format.
+
+use crate::{
+  Ulid,
+  adapter::{
+    Descending, DescendingRef,
+    Lowercase, LowercaseRef,
+    Uppercase, UppercaseRef,
+  },
+};
+
+use serde::{
+  de::{self, Deserialize, Deserializer},
+  ser::{Serialize, Serializer},
+};
+
+use core::fmt;
+
+impl Serialize for Lowercase {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    super::lowercase::serialize(self.as_ulid(), ser)
+  }
+}
+
+impl<'a> Serialize for LowercaseRef<'a> {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    super::lowercase::serialize(self.as_ulid(), ser)
+  }
+}
+
+impl<'de> Deserialize<'de> for Lowercase {
+  fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>,
+  {
+    super::lowercase::deserialize(de).map(Lowercase::from_ulid)
+  }
+}
+
+impl Serialize for Uppercase {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    super::uppercase::serialize(self.as_ulid(), ser)
+  }
+}
+
+impl<'a> Serialize for UppercaseRef<'a> {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    super::uppercase::serialize(self.as_ulid(), ser)
+  }
+}
+
+impl<'de> Deserialize<'de> for Uppercase {
+  fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>,
+  {
+    super::uppercase::deserialize(de).map(Uppercase::from_ulid)
+  }
+}
+
+impl Serialize for Descending {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    ser.collect_str(self)
+  }
+}
+
+impl<'a> Serialize for DescendingRef<'a> {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    ser.collect_str(self)
+  }
+}
+
+struct DescendingVisitor;
+
+impl<'v> de::Visitor<'v> for DescendingVisitor {
+  type Value = Descending;
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "a descending ULID string")
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Descending, E> {
+    Ulid::parse_descending_str(value)
+      .map(Descending::from_ulid)
+      .map_err(E::custom)
+  }
+}
+
+impl<'de> Deserialize<'de> for Descending {
+  fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>,
+  {
+    de.deserialize_str(DescendingVisitor)
+  }
+}
@@ -0,0 +1,23 @@
+//! Serialize a [`Ulid`] as a byte string of 16 bytes.
+//!
+//! This is the default representation in formats that are not human-readable.
+
+use crate::Ulid;
+
+use serde::{Deserializer, Serializer};
+
+/// Serializes a [`Ulid`] as a byte string.
+pub fn serialize<S>(ulid: &Ulid, ser: S) -> Result<S::Ok, S::Error>
+  where S: Serializer,
+{
+  ser.serialize_bytes(ulid.as_bytes())
+}
+
+/// Deserializes a [`Ulid`] from a byte string.
+pub fn deserialize<'de, D>(de: D) -> Result<Ulid, D::Error>
+  where D: Deserializer<'de>,
+{
+  de.deserialize_bytes(super::UlidVisitor)
+}
+
+option_module!();
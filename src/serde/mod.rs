@@ -0,0 +1,218 @@
+//! [`serde`] implementations for [`Ulid`].
+//!
+//! By default, a [`Ulid`] is serialized as a lowercase string in human-readable formats and as raw
+//! bytes in all other formats. The modules in this module can be used with `#[serde(with = ...)]`
+//! to pick a different representation, and each has an `option` submodule for [`Option<Ulid>`].
+//! For compact binary formats without self-description, such as bincode, [`tuple`](mod@tuple)
+//! avoids the length prefix of the default byte string.
+//!
+//! Deserialization is tolerant of the representation: every module, and a plain [`Ulid`], accepts
+//! ULID strings, UUID strings, byte strings, sequences of 16 bytes and integers, as far as the
+//! format can tell them apart. Formats that aren't self-describing only offer what was asked for,
+//! and some can't carry every form, so a few combinations need a specific module:
+//!
+//! - bincode can't read 16 bare bytes (such as a `[u8; 16]`) or a `u128` into a plain [`Ulid`],
+//!   because it expects a length-prefixed byte string. Use [`tuple`](mod@tuple) or
+//!   [`u128`](mod@u128) for those fields.
+//! - JSON integers above [`u64::MAX`] reach a plain [`Ulid`] as floating point and are rejected.
+//!   Use [`u128`](mod@u128), which asks for a 128-bit integer.
+//! - CBOR can't store integers above [`u64::MAX`] at all.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_derive::{Deserialize, Serialize};
+//! use yulid::Ulid;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Event {
+//!   #[serde(with = "yulid::serde::uppercase")]
+//!   id: Ulid,
+//!   #[serde(with = "yulid::serde::uppercase::option")]
+//!   parent: Option<Ulid>,
+//! }
+//!
+//! let event = Event {
+//!   id: Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap(),
+//!   parent: None,
+//! };
+//!
+//! assert_eq!(
+//!   r#"{"id":"05KZBNMT1HNWHS62CRXERMQQAW","parent":null}"#,
+//!   serde_json::to_string(&event).unwrap(),
+//! );
+//! ```
+
+use crate::{parser::Case, Ulid};
+
+use serde::{
+  de::{self, Deserialize, Deserializer},
+  ser::{Serialize, Serializer},
+};
+
+use core::fmt;
+
+/// Generates an `option` module that applies the representation of the enclosing module to an
+/// [`Option<Ulid>`].
+macro_rules! option_module {
+  () => {
+    /// Serialize an [`Option<Ulid>`](crate::Ulid) using the representation of the parent module.
+    pub mod option {
+      use crate::Ulid;
+
+      use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+      struct Wrapper(Ulid);
+
+      impl Serialize for Wrapper {
+        fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+          where S: Serializer,
+        {
+          super::serialize(&self.0, ser)
+        }
+      }
+
+      impl<'de> Deserialize<'de> for Wrapper {
+        fn deserialize<D>(de: D) -> Result<Self, D::Error>
+          where D: Deserializer<'de>,
+        {
+          super::deserialize(de).map(Wrapper)
+        }
+      }
+
+      /// Serializes an [`Option<Ulid>`](crate::Ulid).
+      pub fn serialize<S>(ulid: &Option<Ulid>, ser: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+      {
+        ulid.map(Wrapper).serialize(ser)
+      }
+
+      /// Deserializes an [`Option<Ulid>`](crate::Ulid).
+      pub fn deserialize<'de, D>(de: D) -> Result<Option<Ulid>, D::Error>
+        where D: Deserializer<'de>,
+      {
+        Option::<Wrapper>::deserialize(de).map(|ulid| ulid.map(|Wrapper(ulid)| ulid))
+      }
+    }
+  };
+}
+
+mod adapter;
+pub mod bytes;
+pub mod lowercase;
+pub mod tuple;
+pub mod u128;
+pub mod uppercase;
+pub mod uuid_string;
+
+impl Serialize for Ulid {
+  fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+  {
+    if ser.is_human_readable() {
+      return lowercase::serialize(self, ser)
+    }
+
+    bytes::serialize(self, ser)
+  }
+}
+
+impl<'de> Deserialize<'de> for Ulid {
+  fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>,
+  {
+    if de.is_human_readable() {
+      return de.deserialize_any(UlidVisitor);
+    }
+
+    bytes::deserialize(de)
+  }
+}
+
+/// A visitor that accepts every encoding of a [`Ulid`] that this crate can produce, so that data
+/// written with one representation can be read back with another.
+///
+/// The accepted encodings are:
+///
+/// - a string of 26 base32 digits, in any case
+/// - a hyphenated UUID string, in any case
+/// - a byte string of 16 bytes
+/// - a sequence of 16 [`u8`]s
+/// - a [`u128`](::core::primitive::u128)
+pub(crate) struct UlidVisitor;
+
+impl<'v> de::Visitor<'v> for UlidVisitor {
+  type Value = Ulid;
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "a ULID string, a UUID string, 16 bytes or a 128-bit integer")
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+    if let Some(ulid) = uuid_string::decode(value) {
+      return Ok(ulid);
+    }
+
+    value.parse().map_err(E::custom)
+  }
+
+  fn visit_borrowed_str<E: de::Error>(self, value: &'v str) -> Result<Ulid, E> {
+    self.visit_str(value)
+  }
+
+  fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Ulid, E> {
+    Ulid::from_slice(value).map_err(E::custom)
+  }
+
+  fn visit_borrowed_bytes<E: de::Error>(self, value: &'v [u8]) -> Result<Ulid, E> {
+    self.visit_bytes(value)
+  }
+
+  fn visit_seq<A: de::SeqAccess<'v>>(self, mut seq: A) -> Result<Ulid, A::Error> {
+    let mut bytes = [0; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+      *byte = seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+    }
+
+    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+      return Err(de::Error::invalid_length(17, &self));
+    }
+
+    Ok(Ulid::from_bytes(bytes))
+  }
+
+  fn visit_u64<E: de::Error>(self, value: u64) -> Result<Ulid, E> {
+    Ok(Ulid::from_u128(value.into()))
+  }
+
+  fn visit_u128<E: de::Error>(self, value: ::core::primitive::u128) -> Result<Ulid, E> {
+    Ok(Ulid::from_u128(value))
+  }
+}
+
+/// A visitor that only accepts [`Ulid`] strings in one case.
+pub(crate) struct StrictVisitor(pub(crate) Case);
+
+impl<'v> de::Visitor<'v> for StrictVisitor {
+  type Value = Ulid;
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.0 {
+      Case::Upper => write!(f, "an uppercase ULID string"),
+      Case::Lower => write!(f, "a lowercase ULID string"),
+    }
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+    let wrong_case = match self.0 {
+      Case::Upper => value.bytes().any(|b| b.is_ascii_lowercase()),
+      Case::Lower => value.bytes().any(|b| b.is_ascii_uppercase()),
+    };
+    if wrong_case {
+      return Err(E::invalid_value(de::Unexpected::Str(value), &self));
+    }
+
+    value.parse().map_err(E::custom)
+  }
+}
@@ -0,0 +1,28 @@
+//! Serialize a [`Ulid`] as a tuple of 16 [`u8`]s.
+//!
+//! Unlike a byte string, a tuple has a fixed length, so formats such as bincode don't need to write
+//! a length prefix. A [`Ulid`] takes 16 bytes in bincode this way, rather than the 24 bytes of the
+//! default representation, which prefixes the bytes with a 64-bit length.
+//!
+//! Self-describing formats usually tag every element of a tuple, though, so in CBOR or MessagePack
+//! the default byte string is the smaller of the two.
+
+use crate::Ulid;
+
+use serde::{Deserializer, Serialize, Serializer};
+
+/// Serializes a [`Ulid`] as a tuple of 16 [`u8`]s.
+pub fn serialize<S>(ulid: &Ulid, ser: S) -> Result<S::Ok, S::Error>
+  where S: Serializer,
+{
+  ulid.as_bytes().serialize(ser)
+}
+
+/// Deserializes a [`Ulid`] from a tuple of 16 [`u8`]s.
+pub fn deserialize<'de, D>(de: D) -> Result<Ulid, D::Error>
+  where D: Deserializer<'de>,
+{
+  de.deserialize_tuple(16, super::UlidVisitor)
+}
+
+option_module!();
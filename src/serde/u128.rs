@@ -0,0 +1,21 @@
+//! Serialize a [`Ulid`] as a [`u128`].
+
+use crate::Ulid;
+
+use serde::{Deserializer, Serializer};
+
+/// Serializes a [`Ulid`] as a [`u128`].
+pub fn serialize<S>(ulid: &Ulid, ser: S) -> Result<S::Ok, S::Error>
+  where S: Serializer,
+{
+  ser.serialize_u128(ulid.as_u128())
+}
+
+/// Deserializes a [`Ulid`] from a [`u128`].
+pub fn deserialize<'de, D>(de: D) -> Result<Ulid, D::Error>
+  where D: Deserializer<'de>,
+{
+  de.deserialize_u128(super::UlidVisitor)
+}
+
+option_module!();
@@ -0,0 +1,34 @@
+//! Serialize a [`Ulid`] as an uppercase string.
+//!
+//! Deserialization accepts strings in any case, unless [`deserialize_strict()`] is used.
+
+use crate::{parser::Case, Ulid};
+
+use serde::{Deserializer, Serializer};
+
+/// Serializes a [`Ulid`] as an uppercase string.
+pub fn serialize<S>(ulid: &Ulid, ser: S) -> Result<S::Ok, S::Error>
+  where S: Serializer,
+{
+  ser.collect_str(&ulid.to_uppercase_ref())
+}
+
+/// Deserializes a [`Ulid`] from a string.
+pub fn deserialize<'de, D>(de: D) -> Result<Ulid, D::Error>
+  where D: Deserializer<'de>,
+{
+  de.deserialize_str(super::UlidVisitor)
+}
+
+/// Deserializes a [`Ulid`], or an adapter such as [`Uppercase`](crate::adapter::Uppercase), from
+/// an uppercase string, rejecting strings in any other case.
+///
+/// Use it with `#[serde(deserialize_with = "yulid::serde::uppercase::deserialize_strict")]`.
+pub fn deserialize_strict<'de, D, T>(de: D) -> Result<T, D::Error>
+  where D: Deserializer<'de>,
+        T: From<Ulid>,
+{
+  de.deserialize_str(super::StrictVisitor(Case::Upper)).map(T::from)
+}
+
+option_module!();
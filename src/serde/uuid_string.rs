@@ -0,0 +1,68 @@
+//! Serialize a [`Ulid`] as a hyphenated UUID string.
+//!
+//! This is useful for stores that expect UUID text, such as Postgres `uuid` columns in JSONB
+//! documents. The [`Ulid`]'s bytes are written as-is, so the UUID's version and variant bits are
+//! not set.
+
+use crate::Ulid;
+
+use serde::{Deserializer, Serializer};
+
+use core::fmt;
+
+/// Formats a [`Ulid`]'s bytes as a lowercase hyphenated UUID string.
+pub(crate) struct Hyphenated<'a>(pub(crate) &'a Ulid);
+
+impl fmt::Display for Hyphenated<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let int = self.0.as_u128();
+    write!(
+      f,
+      "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+      int >> 96,
+      (int >> 80) & 0xFFFF,
+      (int >> 64) & 0xFFFF,
+      (int >> 48) & 0xFFFF,
+      int & 0xFFFF_FFFF_FFFF,
+    )
+  }
+}
+
+/// Parses a [`Ulid`]'s bytes from a hyphenated UUID string in any case.
+pub(crate) fn decode(input: &str) -> Option<Ulid> {
+  let bytes = input.as_bytes();
+  if bytes.len() != 36 {
+    return None;
+  }
+
+  let mut int = 0;
+  for (i, &b) in bytes.iter().enumerate() {
+    if let 8 | 13 | 18 | 23 = i {
+      if b != b'-' {
+        return None;
+      }
+      continue;
+    }
+
+    let digit = (b as char).to_digit(16)?;
+    int = int << 4 | digit as u128;
+  }
+
+  Some(Ulid::from_u128(int))
+}
+
+/// Serializes a [`Ulid`] as a hyphenated UUID string.
+pub fn serialize<S>(ulid: &Ulid, ser: S) -> Result<S::Ok, S::Error>
+  where S: Serializer,
+{
+  ser.collect_str(&Hyphenated(ulid))
+}
+
+/// Deserializes a [`Ulid`] from a hyphenated UUID string.
+pub fn deserialize<'de, D>(de: D) -> Result<Ulid, D::Error>
+  where D: Deserializer<'de>,
+{
+  de.deserialize_str(super::UlidVisitor)
+}
+
+option_module!();
@@ -1,11 +1,6 @@
-extern crate test;
-
 use crate::{Bytes, Ulid};
 
-use self::test::Bencher;
-
-#[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use core::time::Duration;
 
 const TEST_BYTES: Bytes = [
   1, 103, 245, 214, 154, 12, // timestamp
@@ -20,11 +15,6 @@ const TEST_FIELD_3: u16 = 27592;
 const TEST_FIELD_4: u32 = 3837945402;
 const TEST_FIELD_5: u32 = 3964860247;
 
-#[bench]
-fn bench_from_str(b: &mut Bencher) {
-  b.iter(|| Ulid::parse_str(TEST_BASE32))
-}
-
 #[test]
 fn from_fields() {
   let expected = Ulid::from_bytes(TEST_BYTES);
@@ -85,6 +75,116 @@ fn to_uppercase() {
   );
 }
 
+#[test]
+fn debug() {
+  let ulid = Ulid::from_bytes(TEST_BYTES);
+
+  assert_eq!(
+    format!("{:?}", ulid),
+    TEST_BASE32,
+  );
+}
+
+#[test]
+fn descending_key() {
+  let ulid = Ulid::from_bytes(TEST_BYTES);
+  let key = ulid.to_descending_key();
+
+  assert_eq!(
+    Ulid::from_descending_key(key),
+    ulid,
+  );
+}
+
+#[test]
+fn descending_order() {
+  let older = Ulid::from_bytes(TEST_BYTES);
+  let newer = Ulid::from_u128(older.as_u128() + 1);
+
+  assert!(older.to_descending_key() > newer.to_descending_key());
+  assert!(older.to_descending().to_string() > newer.to_descending().to_string());
+}
+
+#[test]
+fn descending_adapter_order() {
+  let older = Ulid::from_bytes(TEST_BYTES);
+  let newer = Ulid::from_u128(older.as_u128() + 1);
+
+  assert!(older.to_descending() > newer.to_descending());
+  assert!(older.to_descending_ref() > newer.to_descending_ref());
+
+  let mut keys = [older.to_descending(), newer.to_descending()];
+  keys.sort();
+  assert_eq!(keys, [newer.to_descending(), older.to_descending()]);
+}
+
+#[test]
+fn parse_descending_str() {
+  let ulid = Ulid::from_bytes(TEST_BYTES);
+  let key = ulid.to_descending_ref().to_string();
+
+  assert_eq!(
+    Ulid::parse_descending_str(&key),
+    Ok(ulid),
+  );
+}
+
+#[test]
+fn bucket() {
+  let ulid = Ulid::from_bytes(TEST_BYTES);
+
+  assert_eq!(
+    ulid.bucket(Duration::from_millis(1)),
+    TEST_MILLIS,
+  );
+  assert_eq!(
+    ulid.bucket(Duration::from_secs(60 * 60)),
+    TEST_MILLIS / (60 * 60 * 1000),
+  );
+}
+
+#[test]
+fn bucket_before_epoch() {
+  let width = Duration::from_secs(1);
+
+  assert_eq!(Ulid::from_millis_bytes(-1, [0; 10]).bucket(width), -1);
+  assert_eq!(Ulid::from_millis_bytes(-1000, [0; 10]).bucket(width), -1);
+  assert_eq!(Ulid::from_millis_bytes(-1001, [0; 10]).bucket(width), -2);
+  assert_eq!(Ulid::from_bytes([0xFF; 16]).bucket(width), -1);
+
+  let start = Ulid::from_millis_bytes(-2500, [0; 10]);
+  let end = Ulid::from_millis_bytes(-1, [0; 10]);
+  assert_eq!(Ulid::bucket_range(&start, &end, width), -3..=-1);
+}
+
+#[test]
+#[should_panic(expected = "bucket width must be at least one millisecond")]
+fn bucket_zero_width() {
+  Ulid::from_bytes(TEST_BYTES).bucket(Duration::from_micros(999));
+}
+
+#[test]
+#[should_panic(expected = "bucket width must be a whole number of milliseconds")]
+fn bucket_fractional_width() {
+  Ulid::from_bytes(TEST_BYTES).bucket(Duration::from_micros(1500));
+}
+
+#[test]
+fn bucket_range() {
+  let start = Ulid::from_millis_bytes(999, [0; 10]);
+  let end = Ulid::from_millis_bytes(3000, [0; 10]);
+  let width = Duration::from_secs(1);
+
+  assert_eq!(
+    Ulid::bucket_range(&start, &end, width),
+    0..=3,
+  );
+  assert_eq!(
+    Ulid::bucket_range(&end, &start, width).count(),
+    0,
+  );
+}
+
 #[test]
 fn timestamp_millis() {
   let ulid = Ulid::from_bytes(TEST_BYTES);
@@ -95,18 +195,146 @@ fn timestamp_millis() {
   );
 }
 
+const MIN: Ulid = Ulid::from_u128(0);
+const MAX: Ulid = Ulid::from_bytes([0xFF; 16]);
+const FROM_FIELDS: Ulid = Ulid::from_fields(TEST_FIELD_1, TEST_FIELD_2, TEST_FIELD_3, TEST_FIELD_4, TEST_FIELD_5);
+const FROM_MILLIS: Ulid = Ulid::from_millis_bytes(TEST_MILLIS, [107, 200, 228, 194, 102, 58, 236, 82, 247, 87]);
+const MILLIS: i64 = FROM_MILLIS.as_millis();
+const FIELDS: (u32, u16, u16, u32, u32) = FROM_FIELDS.as_fields();
+const INT: u128 = FROM_FIELDS.as_u128();
+const START_OF_MILLIS: Ulid = Ulid::from_millis_bytes(TEST_MILLIS, [0; 10]);
+const END_OF_MILLIS: Ulid = Ulid::from_millis_bytes(TEST_MILLIS, [0xFF; 10]);
+
+#[test]
+fn const_context() {
+  assert_eq!(MIN, Ulid::default());
+  assert_eq!(MAX.as_u128(), u128::MAX);
+  assert_eq!(FROM_FIELDS, Ulid::from_bytes(TEST_BYTES));
+  assert_eq!(FROM_MILLIS, Ulid::from_bytes(TEST_BYTES));
+  assert_eq!(MILLIS, TEST_MILLIS);
+  assert_eq!(FIELDS, (TEST_FIELD_1, TEST_FIELD_2, TEST_FIELD_3, TEST_FIELD_4, TEST_FIELD_5));
+  assert_eq!(INT, u128::from(Ulid::from_bytes(TEST_BYTES)));
+  assert!(START_OF_MILLIS < FROM_MILLIS && FROM_MILLIS < END_OF_MILLIS);
+  assert_eq!(START_OF_MILLIS.as_millis(), END_OF_MILLIS.as_millis());
+}
+
+#[test]
+fn millis_bounds() {
+  let max_millis = (1 << 47) - 1;
+
+  assert_eq!(Ulid::from_millis_bytes(max_millis, [0; 10]).as_millis(), max_millis);
+  assert_eq!(Ulid::from_millis_bytes(-1, [0; 10]).as_bytes()[..6], [0xFF; 6]);
+  assert_eq!(Ulid::from_millis_bytes(-1, [0; 10]).as_millis(), -1);
+  assert_eq!(Ulid::from_millis_bytes(1 << 48, [0; 10]), MIN);
+  assert_eq!(MAX.as_millis(), -1);
+}
+
+fn ordered_ulids() -> [Ulid; 10] {
+  [
+    Ulid::from_u128(0),
+    Ulid::from_u128(1),
+    Ulid::from_u128(u128::from(u64::MAX >> 1)),
+    Ulid::from_u128(u128::from(u64::MAX)),
+    Ulid::from_u128(1 << 64),
+    Ulid::from_bytes(TEST_BYTES),
+    Ulid::from_u128((1 << 127) - 1),
+    Ulid::from_u128(1 << 127),
+    Ulid::from_u128((1 << 127) | 1 << 63),
+    Ulid::from_u128(u128::MAX),
+  ]
+}
+
+#[test]
+fn u64_pair() {
+  let ulid = Ulid::from_bytes(TEST_BYTES);
+
+  assert_eq!(ulid.to_u64_pair(), (0x0167_F5D6_9A0C_6BC8, 0xE4C2_663A_EC52_F757));
+  for ulid in ordered_ulids().iter() {
+    let (hi, lo) = ulid.to_u64_pair();
+    assert_eq!(Ulid::from_u64_pair(hi, lo), *ulid);
+  }
+}
+
+#[test]
+fn i64_pair() {
+  assert_eq!(Ulid::from_u128(0).to_i64_pair(), (i64::MIN, i64::MIN));
+  assert_eq!(Ulid::from_u128(u128::MAX).to_i64_pair(), (i64::MAX, i64::MAX));
+  for ulid in ordered_ulids().iter() {
+    let (hi, lo) = ulid.to_i64_pair();
+    assert_eq!(Ulid::from_i64_pair(hi, lo), *ulid);
+  }
+}
+
+#[test]
+fn i128() {
+  assert_eq!(Ulid::from_u128(0).to_i128(), i128::MIN);
+  assert_eq!(Ulid::from_u128(u128::MAX).to_i128(), i128::MAX);
+  for ulid in ordered_ulids().iter() {
+    assert_eq!(Ulid::from_i128(ulid.to_i128()), *ulid);
+  }
+}
+
+#[test]
+fn integer_order() {
+  let ulids = ordered_ulids();
+
+  for a in ulids.iter() {
+    for b in ulids.iter() {
+      assert_eq!(a.to_u64_pair().cmp(&b.to_u64_pair()), a.cmp(b));
+      assert_eq!(a.to_i64_pair().cmp(&b.to_i64_pair()), a.cmp(b));
+      assert_eq!(a.to_i128().cmp(&b.to_i128()), a.cmp(b));
+    }
+  }
+}
+
+#[test]
+fn layout() {
+  assert_eq!(core::mem::size_of::<Ulid>(), 16);
+  assert_eq!(core::mem::align_of::<Ulid>(), 1);
+}
+
+#[test]
+fn from_bytes_ref() {
+  let ulid = Ulid::from_bytes_ref(&TEST_BYTES);
+
+  assert_eq!(*ulid, Ulid::from_bytes(TEST_BYTES));
+  assert!(core::ptr::eq(ulid.as_bytes(), &TEST_BYTES));
+}
+
+#[test]
+fn slice_from_bytes() {
+  let mut bytes = [0; 49];
+  bytes[1..17].copy_from_slice(&TEST_BYTES);
+  bytes[33..].copy_from_slice(&[0xFF; 16]);
+
+  // offset by one byte to check that alignment doesn't matter
+  let ulids = Ulid::slice_from_bytes(&bytes[1..]).expect("could not cast");
+
+  assert_eq!(ulids, &[Ulid::from_bytes(TEST_BYTES), Ulid::from_u128(0), Ulid::from_u128(u128::MAX)]);
+  assert_eq!(Ulid::slice_from_bytes(&[]), Ok(&[][..]));
+}
+
+#[test]
+fn slice_from_bytes_with_wrong_length() {
+  assert_eq!(Ulid::slice_from_bytes(&[0; 4]), Err(crate::BytesError::new(16, 4)));
+  assert_eq!(Ulid::slice_from_bytes(&[0; 17]), Err(crate::BytesError::new(32, 17)));
+  assert_eq!(Ulid::slice_from_bytes(&[0; 47]), Err(crate::BytesError::new(48, 47)));
+}
+
 #[cfg(feature = "uuid")]
 mod uuid {
-  use crate::Ulid;
+  use crate::{Ulid, UuidError};
+
+  use uuid::{Uuid, Variant};
 
-  use uuid::Uuid;
+  use std::convert::TryFrom;
 
   const TEST_UUID: [u8; 16] = [167, 60, 243, 221, 130, 30, 78, 250, 175, 236, 174, 157, 240, 232, 161, 205];
 
   #[test]
   fn ulid_from_uuid() {
     let uuid = Uuid::from_bytes(TEST_UUID);
-    let ulid = Ulid::from(uuid);
+    let ulid = Ulid::from_uuid(uuid);
 
     assert_eq!(
       uuid.as_bytes(),
@@ -124,16 +352,103 @@ mod uuid {
       ulid.as_bytes(),
     );
   }
+
+  #[test]
+  fn to_uuid_v7() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let uuid = ulid.to_uuid_v7();
+
+    assert_eq!(uuid.get_version_num(), 7);
+    assert_eq!(uuid.get_variant(), Variant::RFC4122);
+    assert_eq!(uuid.get_timestamp().map(|ts| ts.to_unix()), Some((1546017741, 324_000_000)));
+
+    // only the version and variant bits differ
+    assert_eq!(
+      ulid.as_u128() ^ uuid.as_u128(),
+      (ulid.as_u128() ^ uuid.as_u128()) & 0x0000_0000_0000_F000_C000_0000_0000_0000,
+    );
+  }
+
+  #[test]
+  fn try_from_uuid_v7() {
+    let uuid = Uuid::now_v7();
+    let ulid = Ulid::try_from(uuid);
+
+    assert_eq!(
+      ulid.map(|ulid| ulid.to_uuid()),
+      Ok(uuid),
+    );
+    assert_eq!(
+      Ulid::try_from(Ulid::from_bytes(super::TEST_BYTES).to_uuid_v7()).map(|ulid| ulid.as_millis()),
+      Ok(super::TEST_MILLIS),
+    );
+  }
+
+  #[test]
+  fn try_from_uuid_v4() {
+    let uuid = Uuid::from_bytes(TEST_UUID);
+
+    assert_eq!(
+      Ulid::try_from(uuid),
+      Err(UuidError::new(uuid)),
+    );
+    assert_eq!(
+      UuidError::new(uuid).to_string(),
+      "expected a version 7 UUID, found version 4 with the RFC4122 variant",
+    );
+  }
+}
+
+#[cfg(feature = "ulid-interop")]
+mod ulid_interop {
+  use crate::Ulid;
+
+  #[test]
+  fn binary_forms_agree() {
+    let theirs = ulid::Ulid::from_bytes(super::TEST_BYTES);
+    let ours = Ulid::from(theirs);
+
+    assert_eq!(ours.as_bytes(), &theirs.to_bytes());
+    assert_eq!(ours.as_u128(), theirs.0);
+    assert_eq!(ours.as_millis() as u64, theirs.timestamp_ms());
+    assert_eq!(ulid::Ulid::from(ours), theirs);
+  }
+
+  #[test]
+  fn string_forms_differ() {
+    let ours = Ulid::from_bytes(super::TEST_BYTES);
+    let theirs = ulid::Ulid::from(ours);
+
+    // the padding bits are at the start of the ulid crate's strings and at the end of ours
+    assert_eq!(theirs.to_string(), "01CZTXD6GCDF4E9GK67BP55XTQ");
+    assert_ne!(theirs.to_string().to_lowercase(), ours.to_string());
+
+    // so exchanging strings gives different values
+    assert_ne!(Ulid::parse_str(&theirs.to_string()), Ok(ours));
+  }
+
+  #[test]
+  fn string_forms_agree_without_padding_bits() {
+    let ours = Ulid::from_u128(0);
+    let theirs = ulid::Ulid::from(ours);
+
+    assert_eq!(theirs.to_string().to_lowercase(), ours.to_string());
+  }
 }
 
 #[cfg(feature = "serde")]
 mod serde {
   extern crate serde_derive;
 
-  use crate::Ulid;
+  use crate::{
+    Ulid,
+    adapter::{Descending, Lowercase, Uppercase},
+  };
 
   use self::serde_derive::{Deserialize, Serialize};
 
+  use serde::de::DeserializeOwned;
+
   const TEST_JSON: &str = r#"{"id":"05kzbnmt1hnwhs62crxermqqaw"}"#;
   const TEST_CBOR: &[u8] = &[161, 98, 105, 100, 80, 1, 103, 245, 214, 154, 12, 107, 200, 228, 194, 102, 58, 236, 82, 247, 87];
 
@@ -198,35 +513,1693 @@ mod serde {
       TEST_CBOR,
     );
   }
-}
 
-#[cfg(feature = "std")]
-mod std_support {
-  use crate::Ulid;
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct With {
+    #[serde(with = "crate::serde::uppercase")]
+    uppercase: Ulid,
+    #[serde(with = "crate::serde::lowercase")]
+    lowercase: Ulid,
+    #[serde(with = "crate::serde::uuid_string")]
+    uuid_string: Ulid,
+    #[serde(with = "crate::serde::tuple")]
+    tuple: Ulid,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct WithOption {
+    #[serde(with = "crate::serde::uppercase::option")]
+    uppercase: Option<Ulid>,
+    #[serde(with = "crate::serde::lowercase::option")]
+    lowercase: Option<Ulid>,
+    #[serde(with = "crate::serde::uuid_string::option")]
+    uuid_string: Option<Ulid>,
+    #[serde(with = "crate::serde::tuple::option")]
+    tuple: Option<Ulid>,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct WithBytes {
+    #[serde(with = "crate::serde::bytes")]
+    id: Ulid,
+    #[serde(with = "crate::serde::bytes::option")]
+    parent: Option<Ulid>,
+  }
 
-  use super::test::Bencher;
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct WithU128 {
+    #[serde(with = "crate::serde::u128")]
+    id: Ulid,
+    #[serde(with = "crate::serde::u128::option")]
+    parent: Option<Ulid>,
+  }
 
-  use chrono::{TimeZone, Utc};
+  fn with() -> With {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    With {
+      uppercase: ulid,
+      lowercase: ulid,
+      uuid_string: ulid,
+      tuple: ulid,
+    }
+  }
 
-  #[bench]
-  fn create_new(b: &mut Bencher) {
-    b.iter(|| Ulid::new())
+  fn with_option(ulid: Option<Ulid>) -> WithOption {
+    WithOption {
+      uppercase: ulid,
+      lowercase: ulid,
+      uuid_string: ulid,
+      tuple: ulid,
+    }
   }
 
-  #[bench]
-  fn create_static_timestamp(b: &mut Bencher) {
-    let now = Utc::now();
-    b.iter(|| Ulid::from_timestamp(now))
+  #[test]
+  fn with_to_json() {
+    let json = serde_json::to_value(with()).expect("could not serialise");
+
+    assert_eq!(json["uppercase"], super::TEST_BASE32.to_uppercase());
+    assert_eq!(json["lowercase"], super::TEST_BASE32);
+    assert_eq!(json["uuid_string"], "0167f5d6-9a0c-6bc8-e4c2-663aec52f757");
+    assert_eq!(json["tuple"], serde_json::json!(super::TEST_BYTES));
   }
 
   #[test]
-  fn timestamp() {
+  fn with_json_round_trip() {
+    let json = serde_json::to_string(&with()).expect("could not serialise");
+    let result: With = serde_json::from_str(&json).expect("could not deserialise");
+
+    assert_eq!(
+      result,
+      with(),
+    );
+  }
+
+  #[test]
+  fn with_cbor_round_trip() {
+    let cbor = serde_cbor::to_vec(&with()).expect("could not serialise");
+    let result: With = serde_cbor::from_slice(&cbor).expect("could not deserialise");
+
+    assert_eq!(
+      result,
+      with(),
+    );
+  }
+
+  #[test]
+  fn with_option_round_trip() {
+    for ulid in &[None, Some(Ulid::from_bytes(super::TEST_BYTES))] {
+      let expected = with_option(*ulid);
+
+      let json = serde_json::to_string(&expected).expect("could not serialise");
+      let result: WithOption = serde_json::from_str(&json).expect("could not deserialise");
+      assert_eq!(result, expected);
+
+      let cbor = serde_cbor::to_vec(&expected).expect("could not serialise");
+      let result: WithOption = serde_cbor::from_slice(&cbor).expect("could not deserialise");
+      assert_eq!(result, expected);
+    }
+  }
+
+  #[test]
+  fn with_bytes_cbor_round_trip() {
     let ulid = Ulid::from_bytes(super::TEST_BYTES);
-    let ts = Utc.timestamp_millis(super::TEST_MILLIS);
+    let expected = WithBytes {
+      id: ulid,
+      parent: Some(ulid),
+    };
+
+    let cbor = serde_cbor::to_vec(&expected).expect("could not serialise");
+    let result: WithBytes = serde_cbor::from_slice(&cbor).expect("could not deserialise");
 
     assert_eq!(
-      ulid.as_timestamp(),
-      ts,
+      result,
+      expected,
+    );
+  }
+
+  #[test]
+  fn with_u128_json_round_trip() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let expected = WithU128 {
+      id: ulid,
+      parent: None,
+    };
+
+    let json = serde_json::to_string(&expected).expect("could not serialise");
+    let result: WithU128 = serde_json::from_str(&json).expect("could not deserialise");
+
+    assert_eq!(
+      json,
+      format!(r#"{{"id":{},"parent":null}}"#, ulid.as_u128()),
+    );
+    assert_eq!(
+      result,
+      expected,
+    );
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Legacy {
+    id: [u8; 16],
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Sequence {
+    id: Vec<u8>,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Integer {
+    id: u64,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct U128 {
+    id: u128,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct WithU128Id {
+    #[serde(with = "crate::serde::u128")]
+    id: Ulid,
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct WithTuple {
+    #[serde(with = "crate::serde::tuple")]
+    id: Ulid,
+  }
+
+  fn json<T: serde::Serialize, U: DeserializeOwned>(value: &T) -> U {
+    let json = serde_json::to_vec(value).expect("could not serialise");
+    serde_json::from_slice(&json).expect("could not deserialise")
+  }
+
+  fn cbor<T: serde::Serialize, U: DeserializeOwned>(value: &T) -> U {
+    let cbor = serde_cbor::to_vec(value).expect("could not serialise");
+    serde_cbor::from_slice(&cbor).expect("could not deserialise")
+  }
+
+  fn bincode<T: serde::Serialize, U: DeserializeOwned>(value: &T) -> U {
+    let bincode = bincode::serialize(value).expect("could not serialise");
+    bincode::deserialize(&bincode).expect("could not deserialise")
+  }
+
+  fn rmp<T: serde::Serialize, U: DeserializeOwned>(value: &T) -> U {
+    let rmp = rmp_serde::to_vec(value).expect("could not serialise");
+    rmp_serde::from_slice(&rmp).expect("could not deserialise")
+  }
+
+  fn test() -> Test {
+    Test {
+      id: Ulid::from_bytes(super::TEST_BYTES),
+    }
+  }
+
+  #[test]
+  fn default_round_trip() {
+    assert_eq!(json::<_, Test>(&test()), test());
+    assert_eq!(cbor::<_, Test>(&test()), test());
+    assert_eq!(bincode::<_, Test>(&test()), test());
+    assert_eq!(rmp::<_, Test>(&test()), test());
+  }
+
+  #[test]
+  fn from_sequence() {
+    let legacy = Legacy {
+      id: super::TEST_BYTES,
+    };
+
+    assert_eq!(json::<_, Test>(&legacy), test());
+    assert_eq!(cbor::<_, Test>(&legacy), test());
+    assert_eq!(rmp::<_, Test>(&legacy), test());
+
+    // bincode only reads a length-prefixed sequence, see `from_bincode_tuple`
+    let sequence = Sequence {
+      id: super::TEST_BYTES.to_vec(),
+    };
+
+    assert_eq!(json::<_, Test>(&sequence), test());
+    assert_eq!(cbor::<_, Test>(&sequence), test());
+    assert_eq!(bincode::<_, Test>(&sequence), test());
+    assert_eq!(rmp::<_, Test>(&sequence), test());
+  }
+
+  #[test]
+  fn from_bincode_tuple() {
+    let legacy = Legacy {
+      id: super::TEST_BYTES,
+    };
+    let bincode = bincode::serialize(&legacy).expect("could not serialise");
+
+    assert!(bincode::deserialize::<Test>(&bincode).is_err());
+    assert_eq!(
+      bincode::deserialize::<WithTuple>(&bincode).expect("could not deserialise").id,
+      test().id,
+    );
+  }
+
+  #[test]
+  fn from_integer() {
+    let small = Ulid::from_u128(12345);
+    let integer = Integer {
+      id: 12345,
+    };
+
+    assert_eq!(json::<_, Test>(&integer).id, small);
+    assert_eq!(cbor::<_, Test>(&integer).id, small);
+    assert_eq!(rmp::<_, Test>(&integer).id, small);
+
+    let bincode = bincode::serialize(&integer).expect("could not serialise");
+    assert!(bincode::deserialize::<Test>(&bincode).is_err());
+  }
+
+  #[test]
+  fn from_u128() {
+    let integer = U128 {
+      id: test().id.as_u128(),
+    };
+
+    assert_eq!(rmp::<_, Test>(&integer), test());
+
+    // JSON reads integers above `u64::MAX` as floats unless asked for a `u128`
+    let json = serde_json::to_string(&integer).expect("could not serialise");
+    assert!(serde_json::from_str::<Test>(&json).is_err());
+    assert_eq!(
+      serde_json::from_str::<WithU128Id>(&json).expect("could not deserialise").id,
+      test().id,
+    );
+
+    let bincode = bincode::serialize(&integer).expect("could not serialise");
+    assert!(bincode::deserialize::<Test>(&bincode).is_err());
+    assert_eq!(
+      bincode::deserialize::<WithU128Id>(&bincode).expect("could not deserialise").id,
+      test().id,
+    );
+
+    assert!(serde_cbor::to_vec(&integer).is_err());
+  }
+
+  #[test]
+  fn from_sequence_with_wrong_length() {
+    let json = r#"{"id":[1,2,3]}"#;
+
+    assert!(serde_json::from_str::<Test>(json).is_err());
+
+    let json = r#"{"id":[1,103,245,214,154,12,107,200,228,194,102,58,236,82,247,87,0]}"#;
+
+    assert!(serde_json::from_str::<Test>(json).is_err());
+  }
+
+  #[test]
+  fn from_uuid_string() {
+    let expected = test();
+
+    let json: Test = serde_json::from_str(r#"{"id":"0167f5d6-9a0c-6bc8-e4c2-663aec52f757"}"#)
+      .expect("could not deserialise");
+    assert_eq!(json, expected);
+
+    let json: Test = serde_json::from_str(r#"{"id":"0167F5D6-9A0C-6BC8-E4C2-663AEC52F757"}"#)
+      .expect("could not deserialise");
+    assert_eq!(json, expected);
+  }
+
+  #[test]
+  fn from_uppercase_string() {
+    let json = format!(r#"{{"id":"{}"}}"#, super::TEST_BASE32.to_uppercase());
+    let json: Test = serde_json::from_str(&json).expect("could not deserialise");
+
+    assert_eq!(json, test());
+  }
+
+  #[test]
+  fn from_borrowed_str() {
+    let ulid: Ulid = serde_json::from_str(&format!(r#""{}""#, super::TEST_BASE32))
+      .expect("could not deserialise");
+
+    assert_eq!(ulid, test().id);
+  }
+
+  #[test]
+  fn from_byte_buf() {
+    let cbor: Test = serde_cbor::from_reader(TEST_CBOR).expect("could not deserialise");
+    let rmp = rmp_serde::to_vec(&test()).expect("could not serialise");
+    let rmp: Test = rmp_serde::from_read(&rmp[..]).expect("could not deserialise");
+
+    assert_eq!(cbor, test());
+    assert_eq!(rmp, test());
+  }
+
+  #[test]
+  fn tuple_round_trip() {
+    let expected = WithTuple {
+      id: Ulid::from_bytes(super::TEST_BYTES),
+    };
+
+    assert_eq!(json::<_, WithTuple>(&expected), expected);
+    assert_eq!(cbor::<_, WithTuple>(&expected), expected);
+    assert_eq!(bincode::<_, WithTuple>(&expected), expected);
+    assert_eq!(rmp::<_, WithTuple>(&expected), expected);
+  }
+
+  #[test]
+  fn tuple_encoded_size() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let with_tuple = WithTuple { id: ulid };
+
+    let bytes = bincode::serialize(&with_tuple).expect("could not serialise");
+    assert_eq!(bytes, super::TEST_BYTES);
+    assert_eq!(bincode::serialize(&Test { id: ulid }).expect("could not serialise").len(), 24);
+
+    let cbor_tuple = serde_cbor::to_vec(&with_tuple).expect("could not serialise");
+    let cbor_bytes = serde_cbor::to_vec(&Test { id: ulid }).expect("could not serialise");
+    assert!(cbor_tuple.len() > cbor_bytes.len());
+  }
+
+  #[test]
+  fn tuple_from_legacy_bincode() {
+    let legacy = Legacy {
+      id: super::TEST_BYTES,
+    };
+    let expected = WithTuple {
+      id: Ulid::from_bytes(super::TEST_BYTES),
+    };
+
+    assert_eq!(bincode::<_, WithTuple>(&legacy), expected);
+  }
+
+  #[test]
+  fn u128_round_trip() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let expected = WithU128 {
+      id: ulid,
+      parent: Some(ulid),
+    };
+
+    assert_eq!(json::<_, WithU128>(&expected), expected);
+    assert_eq!(bincode::<_, WithU128>(&expected), expected);
+    assert_eq!(rmp::<_, WithU128>(&expected), expected);
+  }
+
+  #[test]
+  fn bytes_round_trip() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let expected = WithBytes {
+      id: ulid,
+      parent: Some(ulid),
+    };
+
+    assert_eq!(json::<_, WithBytes>(&expected), expected);
+    assert_eq!(cbor::<_, WithBytes>(&expected), expected);
+    assert_eq!(bincode::<_, WithBytes>(&expected), expected);
+    assert_eq!(rmp::<_, WithBytes>(&expected), expected);
+  }
+
+  #[test]
+  fn strings_from_other_formats() {
+    let expected = with();
+
+    assert_eq!(bincode::<_, With>(&expected), expected);
+    assert_eq!(rmp::<_, With>(&expected), expected);
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Adapters {
+    lowercase: Lowercase,
+    uppercase: Uppercase,
+    descending: Descending,
+  }
+
+  #[derive(Debug, Deserialize, PartialEq)]
+  struct Strict {
+    #[serde(deserialize_with = "crate::serde::uppercase::deserialize_strict")]
+    id: Ulid,
+    #[serde(deserialize_with = "crate::serde::lowercase::deserialize_strict")]
+    lowercase: Lowercase,
+  }
+
+  fn adapters() -> Adapters {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    Adapters {
+      lowercase: ulid.to_lowercase(),
+      uppercase: ulid.to_uppercase(),
+      descending: ulid.to_descending(),
+    }
+  }
+
+  #[test]
+  fn adapters_to_json() {
+    let json = serde_json::to_value(adapters()).expect("could not serialise");
+
+    assert_eq!(json["lowercase"], super::TEST_BASE32);
+    assert_eq!(json["uppercase"], super::TEST_BASE32.to_uppercase());
+    assert_eq!(json["descending"], adapters().descending.to_string());
+  }
+
+  #[test]
+  fn ref_adapters_to_json() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(
+      serde_json::to_value(ulid.to_lowercase_ref()).expect("could not serialise"),
+      super::TEST_BASE32,
+    );
+    assert_eq!(
+      serde_json::to_value(ulid.to_uppercase_ref()).expect("could not serialise"),
+      super::TEST_BASE32.to_uppercase(),
+    );
+    assert_eq!(
+      serde_json::to_value(ulid.to_descending_ref()).expect("could not serialise"),
+      ulid.to_descending().to_string(),
+    );
+  }
+
+  #[test]
+  fn adapters_round_trip() {
+    assert_eq!(json::<_, Adapters>(&adapters()), adapters());
+    assert_eq!(cbor::<_, Adapters>(&adapters()), adapters());
+    assert_eq!(bincode::<_, Adapters>(&adapters()), adapters());
+    assert_eq!(rmp::<_, Adapters>(&adapters()), adapters());
+  }
+
+  #[test]
+  fn adapters_accept_any_case() {
+    let json = format!(r#""{}""#, super::TEST_BASE32);
+    let uppercase: Uppercase = serde_json::from_str(&json).expect("could not deserialise");
+
+    assert_eq!(
+      uppercase.into_ulid(),
+      Ulid::from_bytes(super::TEST_BYTES),
+    );
+  }
+
+  #[test]
+  fn strict_case() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let json = format!(
+      r#"{{"id":"{}","lowercase":"{}"}}"#,
+      super::TEST_BASE32.to_uppercase(),
+      super::TEST_BASE32,
+    );
+
+    assert_eq!(
+      serde_json::from_str::<Strict>(&json).expect("could not deserialise"),
+      Strict {
+        id: ulid,
+        lowercase: ulid.to_lowercase(),
+      },
+    );
+
+    let json = format!(
+      r#"{{"id":"{}","lowercase":"{}"}}"#,
+      super::TEST_BASE32,
+      super::TEST_BASE32,
+    );
+
+    assert!(serde_json::from_str::<Strict>(&json).is_err());
+  }
+
+  #[test]
+  fn uuid_string_rejects_invalid() {
+    let result = crate::serde::uuid_string::deserialize(
+      serde_json::Value::from("0167f5d6-9a0c-6bc8-e4c2+663aec52f757"),
+    );
+
+    assert!(result.is_err());
+  }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars {
+  use crate::{
+    Ulid,
+    adapter::{Lowercase, UppercaseRef},
+  };
+
+  use schemars::schema_for;
+
+  #[test]
+  fn ulid_schema() {
+    let schema = serde_json::to_value(schema_for!(Ulid)).expect("could not serialise");
+
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["format"], "ulid");
+    assert_eq!(schema["minLength"], 26);
+    assert_eq!(schema["maxLength"], 26);
+    assert_eq!(schema["pattern"], "^[0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{26}$");
+  }
+
+  #[test]
+  fn adapter_schemas() {
+    let lowercase = serde_json::to_value(schema_for!(Lowercase)).expect("could not serialise");
+    let uppercase = serde_json::to_value(schema_for!(UppercaseRef)).expect("could not serialise");
+
+    assert_eq!(lowercase["pattern"], "^[0-9a-hjkmnp-tv-z]{26}$");
+    assert_eq!(uppercase["pattern"], "^[0-9A-HJKMNP-TV-Z]{26}$");
+  }
+
+  /// Checks `value` against a schema pattern of the form `^[<class>]{26}$`.
+  #[cfg(feature = "serde")]
+  fn matches_pattern(pattern: &serde_json::Value, value: &str) -> bool {
+    let class = pattern.as_str()
+      .and_then(|pattern| pattern.strip_prefix("^["))
+      .and_then(|pattern| pattern.strip_suffix("]{26}$"))
+      .expect("unexpected pattern")
+      .as_bytes();
+
+    let mut allowed = Vec::new();
+    let mut i = 0;
+    while i < class.len() {
+      if class.get(i + 1) == Some(&b'-') {
+        allowed.extend(class[i]..=class[i + 2]);
+        i += 3;
+      } else {
+        allowed.push(class[i]);
+        i += 1;
+      }
+    }
+
+    value.len() == 26 && value.bytes().all(|b| allowed.contains(&b))
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn pattern_matches_serialized() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let schema = serde_json::to_value(schema_for!(Ulid)).expect("could not serialise");
+    let serialized = serde_json::to_value(ulid).expect("could not serialise");
+    assert!(matches_pattern(&schema["pattern"], serialized.as_str().unwrap()));
+    assert!(matches_pattern(&schema["pattern"], &ulid.to_uppercase().to_string()));
+    assert!(!matches_pattern(&schema["pattern"], "05kzbnmt1hnwhs62crxermqqau"));
+
+    let schema = serde_json::to_value(schema_for!(Lowercase)).expect("could not serialise");
+    let serialized = serde_json::to_value(ulid.to_lowercase()).expect("could not serialise");
+    assert!(matches_pattern(&schema["pattern"], serialized.as_str().unwrap()));
+    assert!(!matches_pattern(&schema["pattern"], &ulid.to_uppercase().to_string()));
+
+    let schema = serde_json::to_value(schema_for!(UppercaseRef)).expect("could not serialise");
+    let serialized = serde_json::to_value(ulid.to_uppercase_ref()).expect("could not serialise");
+    assert!(matches_pattern(&schema["pattern"], serialized.as_str().unwrap()));
+    assert!(!matches_pattern(&schema["pattern"], &ulid.to_lowercase().to_string()));
+  }
+}
+
+#[cfg(feature = "async-graphql")]
+mod graphql {
+  use crate::Ulid;
+
+  use async_graphql::{
+    EmptyMutation, EmptySubscription, Object, ScalarType, Schema, SDLExportOptions, Value,
+  };
+
+  struct Query;
+
+  #[Object]
+  impl Query {
+    async fn echo(&self, id: Ulid) -> Ulid {
+      id
+    }
+  }
+
+  #[test]
+  fn parse() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(
+      <Ulid as ScalarType>::parse(Value::String(super::TEST_BASE32.to_uppercase())).ok(),
+      Some(ulid),
+    );
+    assert!(<Ulid as ScalarType>::parse(Value::String("not a ulid".to_string())).is_err());
+    assert!(<Ulid as ScalarType>::parse(Value::Boolean(true)).is_err());
+  }
+
+  #[test]
+  fn to_value() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(
+      ScalarType::to_value(&ulid),
+      Value::String(super::TEST_BASE32.to_string()),
+    );
+  }
+
+  #[test]
+  fn sdl() {
+    let sdl = Schema::new(Query, EmptyMutation, EmptySubscription)
+      .sdl_with_options(SDLExportOptions::new().include_specified_by());
+
+    assert!(sdl.contains(r#"scalar ULID @specifiedBy(url: "https://github.com/ulid/spec")"#));
+    assert!(sdl.contains("echo(id: ULID!): ULID!"));
+  }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite {
+  use crate::{
+    Ulid,
+    adapter::{Descending, Lowercase, Uppercase},
+  };
+
+  use rusqlite::{params, Connection};
+
+  fn ulids() -> Vec<Ulid> {
+    vec![
+      Ulid::from_bytes(super::TEST_BYTES),
+      Ulid::from_u128(u128::MAX),
+      Ulid::from_u128(0),
+      Ulid::from_u128(Ulid::from_bytes(super::TEST_BYTES).as_u128() + 1),
+      Ulid::from_u128(1 << 127),
+      Ulid::from_u128((1 << 127) - 1),
+      Ulid::from_millis_bytes(super::TEST_MILLIS, [0xFF; 10]),
+      Ulid::from_millis_bytes(super::TEST_MILLIS - 1, [0x80; 10]),
+    ]
+  }
+
+  fn connection() -> Connection {
+    let conn = Connection::open_in_memory().expect("could not open database");
+    conn.execute_batch("
+      CREATE TABLE blobs (id BLOB PRIMARY KEY NOT NULL);
+      CREATE TABLE texts (id TEXT PRIMARY KEY NOT NULL, upper TEXT NOT NULL, descending TEXT NOT NULL);
+    ").expect("could not create tables");
+    conn
+  }
+
+  #[test]
+  fn blob_round_trip() {
+    let conn = connection();
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    conn.execute("INSERT INTO blobs (id) VALUES (?1)", params![ulid]).expect("could not insert");
+    let result: Ulid = conn.query_row("SELECT id FROM blobs", [], |row| row.get(0))
+      .expect("could not select");
+
+    assert_eq!(result, ulid);
+  }
+
+  #[test]
+  fn blob_index_order() {
+    let conn = connection();
+    for ulid in ulids() {
+      conn.execute("INSERT INTO blobs (id) VALUES (?1)", params![ulid]).expect("could not insert");
+    }
+
+    let mut stmt = conn.prepare("SELECT id FROM blobs ORDER BY id").expect("could not prepare");
+    let result: Vec<Ulid> = stmt.query_map([], |row| row.get(0))
+      .expect("could not select")
+      .collect::<Result<_, _>>()
+      .expect("could not read");
+
+    let mut expected = ulids();
+    expected.sort();
+
+    assert_eq!(result, expected);
+  }
+
+  #[test]
+  fn text_index_order() {
+    let conn = connection();
+    for ulid in ulids() {
+      conn.execute(
+        "INSERT INTO texts (id, upper, descending) VALUES (?1, ?2, ?3)",
+        params![ulid.to_lowercase(), ulid.to_uppercase(), ulid.to_descending()],
+      ).expect("could not insert");
+    }
+
+    let mut expected = ulids();
+    expected.sort();
+
+    for column in &["id", "upper"] {
+      let mut stmt = conn.prepare(&format!("SELECT id FROM texts ORDER BY {}", column))
+        .expect("could not prepare");
+      let result: Vec<Ulid> = stmt.query_map([], |row| row.get::<_, Lowercase>(0))
+        .expect("could not select")
+        .map(|ulid| ulid.map(Lowercase::into_ulid))
+        .collect::<Result<_, _>>()
+        .expect("could not read");
+
+      assert_eq!(result, expected);
+    }
+
+    let mut stmt = conn.prepare("SELECT upper, descending FROM texts ORDER BY descending")
+      .expect("could not prepare");
+    let result: Vec<(Uppercase, Descending)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+      .expect("could not select")
+      .collect::<Result<_, _>>()
+      .expect("could not read");
+
+    expected.reverse();
+
+    assert_eq!(
+      result.iter().map(|(upper, _)| upper.into_ulid()).collect::<Vec<_>>(),
+      expected,
+    );
+    assert_eq!(
+      result.iter().map(|(_, descending)| descending.into_ulid()).collect::<Vec<_>>(),
+      expected,
+    );
+  }
+
+  #[test]
+  fn blob_with_wrong_length() {
+    let conn = connection();
+
+    let result = conn.query_row("SELECT x'01020304'", [], |row| row.get::<_, Ulid>(0))
+      .map_err(|e| e.to_string());
+
+    assert!(result.unwrap_err().ends_with("invalid bytes length: expected 16, found 4"));
+  }
+
+  #[test]
+  fn text_with_wrong_length() {
+    let conn = connection();
+
+    let result = conn.query_row("SELECT '05kzbnmt'", [], |row| row.get::<_, Lowercase>(0))
+      .map_err(|e| e.to_string());
+
+    assert!(result.unwrap_err().ends_with("invalid length: expected 26, found 8"));
+  }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+mod diesel_support {
+  use crate::Ulid;
+
+  use diesel::{
+    prelude::*,
+    sql_types::{Binary, Text},
+    sqlite::SqliteConnection,
+  };
+
+  diesel::table! {
+    items (id) {
+      id -> Binary,
+      text -> Text,
+      parent -> Nullable<Binary>,
+    }
+  }
+
+  #[derive(Debug, PartialEq, Queryable, Insertable)]
+  #[diesel(table_name = items)]
+  struct Item {
+    id: Ulid,
+    text: Ulid,
+    parent: Option<Ulid>,
+  }
+
+  fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").expect("could not open database");
+    diesel::sql_query("CREATE TABLE items (id BLOB PRIMARY KEY NOT NULL, text TEXT NOT NULL, parent BLOB)")
+      .execute(&mut conn)
+      .expect("could not create table");
+    conn
+  }
+
+  #[test]
+  fn round_trip() {
+    let mut conn = connection();
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let items = vec![
+      Item { id: ulid, text: ulid, parent: None },
+      Item { id: Ulid::from_u128(ulid.as_u128() + 1), text: Ulid::from_u128(0), parent: Some(ulid) },
+    ];
+
+    diesel::insert_into(items::table)
+      .values(&items)
+      .execute(&mut conn)
+      .expect("could not insert");
+    let result: Vec<Item> = items::table.order(items::id).load(&mut conn).expect("could not select");
+
+    assert_eq!(result, items);
+  }
+
+  #[test]
+  fn filter_by_binary_and_text() {
+    let mut conn = connection();
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    diesel::insert_into(items::table)
+      .values(&Item { id: ulid, text: ulid, parent: None })
+      .execute(&mut conn)
+      .expect("could not insert");
+
+    let by_id: Ulid = items::table.select(items::text).filter(items::id.eq(ulid)).first(&mut conn)
+      .expect("could not select by id");
+    let by_text: Ulid = items::table.select(items::id).filter(items::text.eq(&ulid)).first(&mut conn)
+      .expect("could not select by text");
+
+    assert_eq!(by_id, ulid);
+    assert_eq!(by_text, ulid);
+  }
+
+  #[test]
+  fn text_is_lowercase() {
+    let mut conn = connection();
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let text: String = diesel::select(ulid.into_sql::<Text>()).get_result(&mut conn)
+      .expect("could not select");
+
+    assert_eq!(text, super::TEST_BASE32);
+  }
+
+  #[test]
+  fn binary_with_wrong_length() {
+    let mut conn = connection();
+
+    let result = diesel::select(vec![1u8, 2, 3, 4].into_sql::<Binary>()).get_result::<Ulid>(&mut conn)
+      .map_err(|e| std::error::Error::source(&e).map(ToString::to_string));
+
+    assert_eq!(result.unwrap_err().as_deref(), Some("invalid bytes length: expected 16, found 4"));
+  }
+
+  #[test]
+  fn text_with_wrong_length() {
+    let mut conn = connection();
+
+    let result = diesel::select("05kzbnmt".into_sql::<Text>()).get_result::<Ulid>(&mut conn)
+      .map_err(|e| std::error::Error::source(&e).map(ToString::to_string));
+
+    assert_eq!(result.unwrap_err().as_deref(), Some("invalid length: expected 26, found 8"));
+  }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+  use crate::Ulid;
+
+  use bytes::BytesMut;
+  use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+  fn to_sql(ulid: Ulid, ty: &Type) -> BytesMut {
+    let mut out = BytesMut::new();
+    let is_null = ulid.to_sql_checked(ty, &mut out).expect("could not encode");
+    assert!(matches!(is_null, IsNull::No));
+    out
+  }
+
+  #[test]
+  fn uuid_and_bytea() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    for ty in &[Type::UUID, Type::BYTEA] {
+      let out = to_sql(ulid, ty);
+      assert_eq!(&out[..], &super::TEST_BYTES[..]);
+      assert_eq!(Ulid::from_sql(ty, &out).expect("could not decode"), ulid);
+    }
+  }
+
+  #[test]
+  fn text() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    for ty in &[Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+      let out = to_sql(ulid, ty);
+      assert_eq!(&out[..], super::TEST_BASE32.as_bytes());
+      assert_eq!(Ulid::from_sql(ty, &out).expect("could not decode"), ulid);
+    }
+
+    assert_eq!(Ulid::from_sql(&Type::TEXT, b"05KZBNMT1HNWHS62CRXERMQQAW").expect("could not decode"), ulid);
+  }
+
+  #[test]
+  fn accepts() {
+    for ty in &[Type::UUID, Type::BYTEA, Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+      assert!(<Ulid as ToSql>::accepts(ty));
+      assert!(<Ulid as FromSql>::accepts(ty));
+    }
+    for ty in &[Type::INT8, Type::JSON, Type::UUID_ARRAY] {
+      assert!(!<Ulid as ToSql>::accepts(ty));
+      assert!(!<Ulid as FromSql>::accepts(ty));
+    }
+
+    let result = Ulid::from_bytes(super::TEST_BYTES).to_sql_checked(&Type::INT8, &mut BytesMut::new());
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn wrong_length() {
+    let result = Ulid::from_sql(&Type::UUID, &[1, 2, 3, 4]).map_err(|e| e.to_string());
+    assert_eq!(result.unwrap_err(), "invalid bytes length: expected 16, found 4");
+
+    let result = Ulid::from_sql(&Type::TEXT, b"05kzbnmt").map_err(|e| e.to_string());
+    assert_eq!(result.unwrap_err(), "invalid length: expected 26, found 8");
+  }
+}
+
+#[cfg(feature = "redis")]
+mod redis {
+  use crate::{
+    Ulid,
+    adapter::{Descending, Lowercase, Uppercase},
+  };
+
+  use redis::{FromRedisValue, ToRedisArgs, Value, VerbatimFormat};
+
+  #[test]
+  fn to_args() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(ulid.to_redis_args(), vec![super::TEST_BYTES.to_vec()]);
+    assert_eq!(ulid.to_lowercase().to_redis_args(), vec![super::TEST_BASE32.as_bytes().to_vec()]);
+    assert_eq!(ulid.to_lowercase_ref().to_redis_args(), vec![super::TEST_BASE32.as_bytes().to_vec()]);
+    assert_eq!(ulid.to_uppercase().to_redis_args(), vec![b"05KZBNMT1HNWHS62CRXERMQQAW".to_vec()]);
+    assert_eq!(ulid.to_descending().to_redis_args(), vec![ulid.to_descending().to_string().into_bytes()]);
+    assert_eq!((ulid, ulid.to_lowercase()).to_redis_args().len(), 2);
+  }
+
+  #[test]
+  fn from_value() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let values = vec![
+      Value::BulkString(super::TEST_BYTES.to_vec()),
+      Value::BulkString(super::TEST_BASE32.as_bytes().to_vec()),
+      Value::BulkString(b"05KZBNMT1HNWHS62CRXERMQQAW".to_vec()),
+      Value::SimpleString(super::TEST_BASE32.to_string()),
+      Value::VerbatimString { format: VerbatimFormat::Text, text: super::TEST_BASE32.to_string() },
+    ];
+
+    for value in &values {
+      assert_eq!(Ulid::from_redis_value(value).expect("could not convert"), ulid);
+    }
+    for value in &values[1..] {
+      assert_eq!(Lowercase::from_redis_value(value).expect("could not convert"), ulid.to_lowercase());
+      assert_eq!(Uppercase::from_redis_value(value).expect("could not convert"), ulid.to_uppercase());
+    }
+
+    let descending = Value::BulkString(ulid.to_descending().to_redis_args().remove(0));
+    assert_eq!(Descending::from_redis_value(&descending).expect("could not convert"), ulid.to_descending());
+  }
+
+  #[test]
+  fn round_trip() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let value = Value::BulkString(ulid.to_redis_args().remove(0));
+    assert_eq!(Ulid::from_redis_value(&value).expect("could not convert"), ulid);
+
+    let values = Value::Array(vec![value.clone(), Value::BulkString(ulid.to_lowercase().to_redis_args().remove(0))]);
+    assert_eq!(Vec::<Ulid>::from_redis_value(&values).expect("could not convert"), vec![ulid, ulid]);
+    assert_eq!(Option::<Ulid>::from_redis_value(&Value::Nil).expect("could not convert"), None);
+  }
+
+  #[test]
+  fn invalid_values() {
+    assert!(Ulid::from_redis_value(&Value::Int(1)).is_err());
+    assert!(Ulid::from_redis_value(&Value::Nil).is_err());
+    assert!(Ulid::from_redis_value(&Value::BulkString(vec![1, 2, 3, 4])).is_err());
+    assert!(Ulid::from_redis_value(&Value::BulkString(vec![0xFF; 26])).is_err());
+    assert!(Lowercase::from_redis_value(&Value::BulkString(super::TEST_BYTES.to_vec())).is_err());
+
+    let error = Ulid::from_redis_value(&Value::SimpleString("05kzbnmt".to_string())).unwrap_err();
+    assert_eq!(error.detail(), Some("invalid length: expected 26, found 8"));
+  }
+}
+
+#[cfg(feature = "prost")]
+mod proto {
+  use crate::{proto, BytesError, Ulid};
+
+  use prost::{bytes::Bytes, Message, Name};
+
+  use std::convert::TryFrom;
+
+  #[test]
+  fn message() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let message = proto::Ulid::from(ulid);
+
+    assert_eq!(message.hi, 0x0167_F5D6_9A0C_6BC8);
+    assert_eq!(message.lo, 0xE4C2_663A_EC52_F757);
+    assert_eq!(Ulid::from(message), ulid);
+  }
+
+  #[test]
+  fn wire_encoding() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let encoded = proto::Ulid::from(ulid).encode_to_vec();
+
+    let mut expected = vec![0x09];
+    expected.extend_from_slice(&[0xC8, 0x6B, 0x0C, 0x9A, 0xD6, 0xF5, 0x67, 0x01]);
+    expected.push(0x11);
+    expected.extend_from_slice(&[0x57, 0xF7, 0x52, 0xEC, 0x3A, 0x66, 0xC2, 0xE4]);
+    assert_eq!(encoded, expected);
+
+    let decoded = proto::Ulid::decode(&encoded[..]).expect("could not decode");
+    assert_eq!(Ulid::from(decoded), ulid);
+
+    assert!(proto::Ulid::from(Ulid::from_u128(0)).encode_to_vec().is_empty());
+    assert_eq!(Ulid::from(proto::Ulid::decode(&[][..]).expect("could not decode")), Ulid::from_u128(0));
+  }
+
+  #[test]
+  fn name() {
+    assert_eq!(proto::Ulid::full_name(), "yulid.v1.Ulid");
+    assert_eq!(proto::Ulid::type_url(), "/yulid.v1.Ulid");
+  }
+
+  #[test]
+  fn bytes_field() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let bytes = Bytes::from(ulid);
+
+    assert_eq!(&bytes[..], &super::TEST_BYTES[..]);
+    assert_eq!(Ulid::try_from(&bytes), Ok(ulid));
+    assert_eq!(Ulid::try_from(bytes), Ok(ulid));
+    assert_eq!(Ulid::try_from(Bytes::from_static(&[1, 2, 3, 4])), Err(BytesError::new(16, 4)));
+    assert_eq!(Ulid::try_from(Bytes::new()), Err(BytesError::new(16, 0)));
+  }
+}
+
+#[cfg(feature = "borsh")]
+mod borsh {
+  use crate::Ulid;
+
+  #[test]
+  fn fixed_encoding() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let bytes = borsh::to_vec(&ulid).expect("could not serialize");
+    assert_eq!(bytes, super::TEST_BYTES);
+    assert_eq!(borsh::to_vec(&super::TEST_BYTES).expect("could not serialize"), bytes);
+    assert_eq!(borsh::from_slice::<Ulid>(&bytes).expect("could not deserialize"), ulid);
+  }
+
+  #[test]
+  fn nested_round_trip() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let value = (Some(ulid), vec![ulid, Ulid::from_u128(0)], None::<Ulid>);
+
+    let bytes = borsh::to_vec(&value).expect("could not serialize");
+    assert_eq!(bytes.len(), (1 + 16) + (4 + 2 * 16) + 1);
+    assert_eq!(borsh::from_slice::<(Option<Ulid>, Vec<Ulid>, Option<Ulid>)>(&bytes).expect("could not deserialize"), value);
+  }
+
+  #[test]
+  fn wrong_length() {
+    assert!(borsh::from_slice::<Ulid>(&[1, 2, 3, 4]).is_err());
+    assert!(borsh::from_slice::<Ulid>(&[0; 17]).is_err());
+  }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+  use crate::Ulid;
+
+  #[test]
+  fn cast() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(bytemuck::cast::<_, Ulid>(super::TEST_BYTES), ulid);
+    assert_eq!(bytemuck::bytes_of(&ulid), &super::TEST_BYTES[..]);
+    assert_eq!(<Ulid as bytemuck::Zeroable>::zeroed(), Ulid::from_u128(0));
+  }
+
+  #[test]
+  fn cast_slice() {
+    let ulids = [Ulid::from_bytes(super::TEST_BYTES), Ulid::from_u128(u128::MAX)];
+    let bytes: &[u8] = bytemuck::cast_slice(&ulids);
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytemuck::cast_slice::<u8, Ulid>(bytes), &ulids);
+    assert!(bytemuck::try_cast_slice::<u8, Ulid>(&bytes[1..]).is_err());
+  }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy {
+  use crate::Ulid;
+
+  use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+  #[test]
+  fn ref_from_bytes() {
+    let ulid = Ulid::ref_from_bytes(&super::TEST_BYTES[..]).expect("could not cast");
+
+    assert_eq!(*ulid, Ulid::from_bytes(super::TEST_BYTES));
+    assert_eq!(ulid.as_bytes(), &super::TEST_BYTES[..]);
+    assert!(Ulid::ref_from_bytes(&super::TEST_BYTES[1..]).is_err());
+    assert_eq!(Ulid::new_zeroed(), Ulid::from_u128(0));
+  }
+
+  #[test]
+  fn slice_from_bytes() {
+    let ulids = [Ulid::from_bytes(super::TEST_BYTES), Ulid::from_u128(u128::MAX)];
+    let bytes = ulids.as_bytes();
+
+    assert_eq!(<[Ulid]>::ref_from_bytes(bytes).expect("could not cast"), &ulids);
+    assert!(<[Ulid]>::ref_from_bytes(&bytes[1..]).is_err());
+  }
+}
+
+#[cfg(feature = "std")]
+mod io {
+  use crate::{
+    io::{ReadUlidExt, WriteUlidExt},
+    Ulid,
+  };
+
+  use std::io::ErrorKind;
+
+  #[test]
+  fn write_and_read() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let mut buf = Vec::new();
+    buf.write_ulid(&ulid).expect("could not write");
+    buf.write_ulid_le(&ulid).expect("could not write");
+
+    let mut reversed = super::TEST_BYTES;
+    reversed.reverse();
+    assert_eq!(&buf[..16], &super::TEST_BYTES[..]);
+    assert_eq!(&buf[16..], &reversed[..]);
+    assert_eq!(&buf[16..], &ulid.as_u128().to_le_bytes()[..]);
+
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_ulid().expect("could not read"), ulid);
+    assert_eq!(reader.read_ulid_le().expect("could not read"), ulid);
+    assert!(reader.is_empty());
+  }
+
+  #[test]
+  fn read_too_short() {
+    let mut reader = &super::TEST_BYTES[1..];
+
+    let error = reader.read_ulid().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+  }
+}
+
+#[cfg(feature = "bytes")]
+mod bytes {
+  use crate::{
+    io::{BufMutUlidExt, BufUlidExt},
+    Ulid,
+  };
+
+  use bytes::{Buf, BufMut, BytesMut};
+
+  #[test]
+  fn put_and_get() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let mut buf = BytesMut::new();
+    buf.put_ulid(&ulid);
+    buf.put_ulid_le(&ulid);
+    buf.put_u128(ulid.as_u128());
+    buf.put_u128_le(ulid.as_u128());
+
+    assert_eq!(&buf[..16], &super::TEST_BYTES[..]);
+    assert_eq!(&buf[..32], &buf[32..]);
+
+    let mut buf = buf.freeze();
+    assert_eq!(buf.get_ulid(), ulid);
+    assert_eq!(buf.get_ulid_le(), ulid);
+    assert_eq!(buf.get_ulid(), ulid);
+    assert_eq!(buf.get_ulid_le(), ulid);
+    assert!(!buf.has_remaining());
+  }
+
+  #[test]
+  #[should_panic]
+  fn get_too_short() {
+    let mut buf = &super::TEST_BYTES[1..];
+    buf.get_ulid();
+  }
+}
+
+#[cfg(feature = "rand_core")]
+mod rand_core {
+  use crate::{clock::TimeSource, Ulid};
+
+  use rand_core::{impls, Error, RngCore};
+
+  use core::num::NonZeroU32;
+
+  /// Yields the random bytes of `TEST_BYTES`.
+  struct TestRng;
+
+  impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+      impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      dest.copy_from_slice(&super::TEST_BYTES[6..6 + dest.len()]);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      self.fill_bytes(dest);
+      Ok(())
+    }
+  }
+
+  struct FailingRng;
+
+  impl RngCore for FailingRng {
+    fn next_u32(&mut self) -> u32 {
+      impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      self.try_fill_bytes(dest).expect("rng failed")
+    }
+
+    fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), Error> {
+      Err(Error::from(NonZeroU32::new(Error::CUSTOM_START).unwrap()))
+    }
+  }
+
+  #[test]
+  fn generate() {
+    let mut clock = || super::TEST_MILLIS;
+
+    let ulid = Ulid::generate(&mut clock, &mut TestRng);
+    assert_eq!(ulid, Ulid::from_bytes(super::TEST_BYTES));
+
+    let ulid = Ulid::try_generate(&mut clock, &mut TestRng).expect("could not generate");
+    assert_eq!(ulid, Ulid::from_bytes(super::TEST_BYTES));
+  }
+
+  #[test]
+  fn generate_reads_clock_each_time() {
+    let mut millis = super::TEST_MILLIS;
+    let mut clock = || {
+      millis += 1;
+      millis
+    };
+
+    let first = Ulid::generate(&mut clock, &mut TestRng);
+    let second = Ulid::generate(&mut clock, &mut TestRng);
+    assert_eq!(first.as_millis(), super::TEST_MILLIS + 1);
+    assert_eq!(second.as_millis(), super::TEST_MILLIS + 2);
+    assert!(first < second);
+  }
+
+  #[test]
+  fn generate_with_dyn() {
+    let clock: &mut dyn TimeSource = &mut || super::TEST_MILLIS;
+    let rng: &mut dyn RngCore = &mut TestRng;
+
+    let ulid = Ulid::generate(clock, rng);
+    assert_eq!(ulid, Ulid::from_bytes(super::TEST_BYTES));
+  }
+
+  #[test]
+  fn try_generate_with_failing_rng() {
+    let error = Ulid::try_generate(&mut || super::TEST_MILLIS, &mut FailingRng).unwrap_err();
+    assert_eq!(error.code(), NonZeroU32::new(Error::CUSTOM_START));
+  }
+
+  #[test]
+  #[should_panic(expected = "rng failed")]
+  fn generate_with_failing_rng() {
+    Ulid::generate(&mut || super::TEST_MILLIS, &mut FailingRng);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn system_clock() {
+    use crate::clock::SystemClock;
+
+    use std::time::SystemTime;
+
+    let before = crate::components::system_time_millis(SystemTime::now());
+    let ulid = Ulid::generate(&mut SystemClock, &mut TestRng);
+    let after = crate::components::system_time_millis(SystemTime::now());
+
+    assert!(before <= ulid.as_millis() && ulid.as_millis() <= after);
+  }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless {
+  use crate::Ulid;
+
+  use heapless::String;
+
+  #[test]
+  fn to_heapless_string() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    let string: String<26> = ulid.into();
+    assert_eq!(string, super::TEST_BASE32);
+
+    let string = String::<26>::from(ulid.to_uppercase());
+    assert_eq!(string, "05KZBNMT1HNWHS62CRXERMQQAW");
+
+    let string = String::<26>::from(ulid.to_lowercase_ref());
+    assert_eq!(string, super::TEST_BASE32);
+
+    let string = String::<26>::from(ulid.to_descending());
+    assert_eq!(Ulid::parse_descending_str(&string), Ok(ulid));
+  }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv {
+  use crate::Ulid;
+
+  use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+
+  #[derive(Debug, PartialEq, Archive, Serialize, Deserialize)]
+  struct Index {
+    id: Ulid,
+    entries: Vec<Ulid>,
+  }
+
+  fn index() -> Index {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    Index {
+      id: ulid,
+      entries: vec![
+        Ulid::from_u128(0),
+        Ulid::from_u128(ulid.as_u128() - 1),
+        ulid,
+        Ulid::from_u128((1 << 127) - 1),
+        Ulid::from_u128(1 << 127),
+        Ulid::from_u128(u128::MAX),
+      ],
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    let index = index();
+
+    let bytes = rkyv::to_bytes::<Error>(&index).expect("could not serialize");
+    let result = rkyv::from_bytes::<Index, Error>(&bytes).expect("could not deserialize");
+
+    assert_eq!(result, index);
+  }
+
+  #[test]
+  fn archived_bytes() {
+    let index = index();
+
+    let bytes = rkyv::to_bytes::<Error>(&index).expect("could not serialize");
+    let archived = rkyv::access::<ArchivedIndex, Error>(&bytes).expect("could not access");
+
+    assert_eq!(archived.id, super::TEST_BYTES);
+    assert_eq!(archived.entries.len(), index.entries.len());
+    for (archived, ulid) in archived.entries.iter().zip(&index.entries) {
+      assert_eq!(archived, ulid.as_bytes());
+    }
+  }
+
+  #[test]
+  fn archived_order() {
+    let index = index();
+
+    let bytes = rkyv::to_bytes::<Error>(&index).expect("could not serialize");
+    let archived = rkyv::access::<ArchivedIndex, Error>(&bytes).expect("could not access");
+
+    for (a, b) in archived.entries.iter().zip(archived.entries.iter().skip(1)) {
+      assert!(a < b);
+    }
+    let position = archived.entries.binary_search(&archived.id);
+    assert_eq!(position, Ok(2));
+  }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+  use crate::{components::system_time_millis, Ulid};
+
+  use std::time::{Duration, UNIX_EPOCH};
+
+  #[test]
+  fn system_time_millis_rounds_down() {
+    assert_eq!(
+      system_time_millis(UNIX_EPOCH + Duration::from_micros(1500)),
+      1,
+    );
+    assert_eq!(
+      system_time_millis(UNIX_EPOCH - Duration::from_micros(1500)),
+      -2,
+    );
+  }
+
+  #[test]
+  fn age_at() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let created = UNIX_EPOCH + Duration::from_millis(super::TEST_MILLIS as u64);
+
+    assert_eq!(
+      ulid.age_at(created + Duration::from_millis(1500)),
+      Some(Duration::from_millis(1500)),
+    );
+    assert_eq!(
+      ulid.age_at(created - Duration::from_millis(1)),
+      None,
+    );
+  }
+
+  #[test]
+  fn elapsed_since() {
+    let earlier = Ulid::from_bytes(super::TEST_BYTES);
+    let later = Ulid::from_millis_bytes(super::TEST_MILLIS + 250, [0; 10]);
+
+    assert_eq!(
+      later.elapsed_since(&earlier),
+      Some(Duration::from_millis(250)),
+    );
+    assert_eq!(
+      earlier.elapsed_since(&later),
+      None,
+    );
+  }
+
+  #[test]
+  fn day_and_hour_bucket_system_time() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    // 2018-12-28T00:00:00Z and 2018-12-28T17:00:00Z
+    assert_eq!(
+      ulid.day_bucket_system_time(),
+      UNIX_EPOCH + Duration::from_secs(1_545_955_200),
+    );
+    assert_eq!(
+      ulid.hour_bucket_system_time(),
+      UNIX_EPOCH + Duration::from_secs(1_546_016_400),
+    );
+
+    let before_epoch = Ulid::from_millis_bytes(-1, [0; 10]);
+    assert_eq!(
+      before_epoch.day_bucket_system_time(),
+      UNIX_EPOCH - Duration::from_secs(24 * 60 * 60),
+    );
+  }
+
+  #[test]
+  fn day_bucket_system_time_matches_bucket() {
+    let day = Duration::from_secs(24 * 60 * 60);
+
+    for millis in [super::TEST_MILLIS, 0, -1, -(1 << 47)] {
+      let ulid = Ulid::from_millis_bytes(millis, [0; 10]);
+      let bucket = ulid.bucket(day);
+
+      let offset = day * bucket.unsigned_abs() as u32;
+      let expected = if bucket < 0 { UNIX_EPOCH - offset } else { UNIX_EPOCH + offset };
+      assert_eq!(ulid.day_bucket_system_time(), expected);
+    }
+  }
+
+  #[test]
+  fn is_older_than_at() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let created = UNIX_EPOCH + Duration::from_millis(super::TEST_MILLIS as u64);
+    let ttl = Duration::from_secs(60);
+
+    assert!(ulid.is_older_than_at(ttl, created + ttl + Duration::from_millis(1)));
+    assert!(!ulid.is_older_than_at(ttl, created + ttl));
+    assert!(!ulid.is_older_than_at(ttl, created - Duration::from_secs(60 * 60)));
+  }
+
+  #[test]
+  fn is_in_future_at() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let created = UNIX_EPOCH + Duration::from_millis(super::TEST_MILLIS as u64);
+    let tolerance = Duration::from_secs(60);
+
+    assert!(ulid.is_in_future_at(tolerance, created - tolerance - Duration::from_millis(1)));
+    assert!(!ulid.is_in_future_at(tolerance, created - tolerance));
+    assert!(!ulid.is_in_future_at(Duration::from_secs(0), created + Duration::from_secs(60 * 60)));
+  }
+
+  #[test]
+  fn queries_with_clock() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let mut clock = || super::TEST_MILLIS + 1500;
+
+    assert_eq!(ulid.age_with(&mut clock), Some(Duration::from_millis(1500)));
+    assert!(ulid.is_older_than_with(Duration::from_secs(1), &mut clock));
+    assert!(!ulid.is_older_than_with(Duration::from_secs(2), &mut clock));
+
+    let mut clock = || super::TEST_MILLIS - 1500;
+
+    assert_eq!(ulid.age_with(&mut clock), None);
+    assert!(ulid.is_in_future_with(Duration::from_secs(1), &mut clock));
+    assert!(!ulid.is_in_future_with(Duration::from_secs(2), &mut clock));
+  }
+
+  #[test]
+  fn is_older_than_and_in_future_use_now() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let future = Ulid::from_millis_bytes((1 << 47) - 1, [0; 10]);
+
+    assert!(ulid.is_older_than(Duration::from_secs(60)));
+    assert!(!ulid.is_in_future(Duration::from_secs(0)));
+    assert!(future.is_in_future(Duration::from_secs(60)));
+    assert!(!future.is_older_than(Duration::from_secs(60)));
+  }
+
+  #[test]
+  fn inspect() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let info = ulid.inspect();
+
+    assert_eq!(info.ulid(), ulid);
+    assert_eq!(info.string(), super::TEST_BASE32);
+    assert_eq!(info.hex(), "0167f5d69a0c6bc8e4c2663aec52f757");
+    assert_eq!(info.as_u128(), ulid.as_u128());
+    assert_eq!(info.millis(), super::TEST_MILLIS);
+    assert_eq!(info.random(), 0x6bc8e4c2663aec52f757);
+    assert!(info.is_valid());
+  }
+
+  #[test]
+  fn inspect_display() {
+    let report = Ulid::from_bytes(super::TEST_BYTES).inspect().to_string();
+
+    assert!(report.starts_with(&format!("ulid:    {}\n", super::TEST_BASE32)));
+    assert!(report.contains("random:  6bc8e4c2663aec52f757\n"));
+    assert!(report.ends_with("valid:   true"));
+  }
+
+  #[test]
+  fn inspect_invalid() {
+    let info = Ulid::from_bytes([0xFF; 16]).inspect();
+
+    assert_eq!(info.millis(), -1);
+    assert!(!info.is_valid());
+    assert!(info.to_string().ends_with("valid:   false"));
+  }
+
+  #[test]
+  fn inspect_time() {
+    let time = |millis| Ulid::from_millis_bytes(millis, [0; 10]).inspect().time().map(str::to_owned);
+
+    assert_eq!(time(super::TEST_MILLIS).as_deref(), Some("2018-12-28T17:22:21.324Z"));
+    assert_eq!(time(1).as_deref(), Some("1970-01-01T00:00:00.001Z"));
+    assert_eq!(time(951_825_600_123).as_deref(), Some("2000-02-29T12:00:00.123Z"));
+    assert_eq!(time(-1).as_deref(), Some("1969-12-31T23:59:59.999Z"));
+    assert_eq!(time((1 << 47) - 1).as_deref(), Some("6429-10-17T02:45:55.327Z"));
+    assert_eq!(time(super::TEST_MILLIS - 24).as_deref(), Some("2018-12-28T17:22:21.300Z"));
+    assert_eq!(time(super::TEST_MILLIS - 324).as_deref(), Some("2018-12-28T17:22:21.000Z"));
+    assert_eq!(time(-(1 << 47)).as_deref(), None);
+  }
+
+  #[test]
+  fn system_time() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let time = UNIX_EPOCH + Duration::from_millis(super::TEST_MILLIS as u64);
+
+    assert_eq!(
+      ulid.as_system_time(),
+      time,
+    );
+  }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+  use crate::Ulid;
+
+  use chrono::{FixedOffset, TimeZone, Utc};
+
+  #[test]
+  fn timestamp() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let ts = Utc.timestamp_millis_opt(super::TEST_MILLIS).unwrap();
+
+    assert_eq!(
+      ulid.as_timestamp(),
+      ts,
+    );
+  }
+
+  #[test]
+  fn timestamp_with_offset() {
+    let ts = FixedOffset::east_opt(5 * 60 * 60)
+      .unwrap()
+      .timestamp_millis_opt(super::TEST_MILLIS)
+      .unwrap();
+    let ulid = Ulid::from_timestamp_bytes(ts, [0; 10]);
+
+    assert_eq!(
+      ulid.as_millis(),
+      super::TEST_MILLIS,
+    );
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn inspect_time() {
+    let info = Ulid::from_bytes(super::TEST_BYTES).inspect();
+
+    assert_eq!(
+      info.time(),
+      Some("2018-12-28T17:22:21.324Z"),
+    );
+  }
+
+  #[test]
+  fn day_bucket() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(
+      ulid.day_bucket(),
+      Utc.with_ymd_and_hms(2018, 12, 28, 0, 0, 0).unwrap(),
+    );
+  }
+
+  #[test]
+  fn hour_bucket() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+
+    assert_eq!(
+      ulid.hour_bucket(),
+      Utc.with_ymd_and_hms(2018, 12, 28, 17, 0, 0).unwrap(),
+    );
+  }
+}
+
+#[cfg(feature = "time")]
+mod time {
+  use crate::Ulid;
+
+  use time::OffsetDateTime;
+
+  #[test]
+  fn offset_date_time() {
+    let ulid = Ulid::from_bytes(super::TEST_BYTES);
+    let ts = OffsetDateTime::from_unix_timestamp_nanos(i128::from(super::TEST_MILLIS) * 1_000_000).unwrap();
+
+    assert_eq!(
+      ulid.as_offset_date_time(),
+      ts,
+    );
+    assert_eq!(
+      Ulid::from_offset_date_time_bytes(ts, [0; 10]).as_millis(),
+      super::TEST_MILLIS,
     );
   }
 }
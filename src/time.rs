@@ -0,0 +1,33 @@
+//! Conversions to and from [`OffsetDateTime`](time::OffsetDateTime)/[`Ulid`].
+
+use crate::Ulid;
+
+use time::OffsetDateTime;
+
+/// Returns the milliseconds since the Unix epoch of an [`OffsetDateTime`], rounding down.
+pub(crate) fn offset_date_time_millis(timestamp: OffsetDateTime) -> i64 {
+  timestamp.unix_timestamp_nanos().div_euclid(1_000_000) as i64
+}
+
+impl Ulid {
+  /// Creates a [`Ulid`] from a timestamp and the provided bytes.
+  #[inline]
+  pub fn from_offset_date_time_bytes(timestamp: OffsetDateTime, bytes: [u8; 10]) -> Self {
+    Ulid::from_millis_bytes(offset_date_time_millis(timestamp), bytes)
+  }
+
+  /// Returns the timestamp portion of this [`Ulid`].
+  ///
+  /// # Panics
+  ///
+  /// This function will panic if the timestamp cannot be represented by [`OffsetDateTime`]. See
+  /// [`Ulid::as_offset_date_time_opt()`] for a non-panicking version.
+  pub fn as_offset_date_time(&self) -> OffsetDateTime {
+    self.as_offset_date_time_opt().expect("timestamp out of bounds for OffsetDateTime")
+  }
+
+  /// Returns the timestamp portion of this [`Ulid`], capturing out-of-bounds values as `None`.
+  pub fn as_offset_date_time_opt(&self) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(self.as_millis()) * 1_000_000).ok()
+  }
+}
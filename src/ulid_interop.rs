@@ -0,0 +1,24 @@
+//! Conversions to and from the [`ulid`] crate's [`Ulid`](ulid::Ulid)/[`Ulid`].
+//!
+//! Both types store the same 128 bits in the same order, so the conversions are lossless and the
+//! binary forms ([`Ulid::as_bytes()`] and [`Ulid::as_u128()`]) always agree.
+//!
+//! The string forms do not agree, however. Encoding 128 bits as 26 base32 digits leaves two bits of
+//! padding. The [`ulid`] crate follows the ULID spec and puts them at the start of the string,
+//! while [`encode`](crate::parser) in this crate puts them at the end. A string produced by one
+//! crate therefore parses to a different value in the other, and strings should not be exchanged
+//! between the two; convert the values instead.
+
+use crate::Ulid;
+
+impl From<ulid::Ulid> for Ulid {
+  fn from(ulid: ulid::Ulid) -> Self {
+    Ulid::from_u128(ulid.0)
+  }
+}
+
+impl From<Ulid> for ulid::Ulid {
+  fn from(ulid: Ulid) -> Self {
+    ulid::Ulid(ulid.as_u128())
+  }
+}
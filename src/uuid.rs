@@ -1,17 +1,93 @@
 //! Conversions to and from [`Uuid`](uuid::Uuid)/[`Ulid`].
+//!
+//! A [`Ulid`] has the same layout as a version 7 UUID: a 48-bit big-endian millisecond timestamp
+//! followed by random bits. A UUIDv7 uses six of those bits for its version and variant, though, so
+//! there are two kinds of conversion:
+//!
+//! - [`Ulid::from_uuid()`], [`Ulid::to_uuid()`] and `From<Ulid> for Uuid` copy the bytes unchanged.
+//!   This round-trips any value, but the resulting [`Uuid`] usually has meaningless version and
+//!   variant bits.
+//! - [`Ulid::to_uuid_v7()`] and `TryFrom<Uuid> for Ulid` produce and accept valid UUIDv7s, keeping
+//!   the timestamp.
 
 use crate::Ulid;
 
-use uuid::Uuid;
+use uuid::{Uuid, Variant};
 
-impl From<Uuid> for Ulid {
-  fn from(uuid: Uuid) -> Self {
-    Ulid::from_bytes(*uuid.as_bytes())
+use core::convert::TryFrom;
+
+impl Ulid {
+  /// Creates a [`Ulid`] from the bytes of a [`Uuid`] of any version, without changing any bits.
+  pub const fn from_uuid(uuid: Uuid) -> Self {
+    Ulid::from_bytes(uuid.into_bytes())
+  }
+
+  /// Creates a [`Uuid`] from the bytes of this [`Ulid`], without changing any bits.
+  ///
+  /// The version and variant bits of the resulting [`Uuid`] are whatever the random portion of the
+  /// [`Ulid`] contained. Use [`Ulid::to_uuid_v7()`] to get a valid UUIDv7 instead.
+  pub const fn to_uuid(&self) -> Uuid {
+    Uuid::from_bytes(*self.as_bytes())
+  }
+
+  /// Creates a version 7 [`Uuid`] from this [`Ulid`].
+  ///
+  /// The timestamp is kept, but six of the random bits are overwritten with the UUID version and
+  /// variant, so converting the result back with `Ulid::try_from` won't give the original [`Ulid`]
+  /// unless those bits already matched.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::convert::TryFrom;
+  /// use yulid::Ulid;
+  ///
+  /// let ulid = Ulid::parse_str("05kzbnmt1hnwhs62crxermqqaw").unwrap();
+  /// let uuid = ulid.to_uuid_v7();
+  ///
+  /// assert_eq!(7, uuid.get_version_num());
+  /// assert_eq!(ulid.as_millis(), Ulid::try_from(uuid).unwrap().as_millis());
+  /// ```
+  pub const fn to_uuid_v7(&self) -> Uuid {
+    let mut bytes = *self.as_bytes();
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    Uuid::from_bytes(bytes)
   }
 }
 
 impl From<Ulid> for Uuid {
   fn from(ulid: Ulid) -> Self {
-    Uuid::from_bytes(*ulid.as_bytes())
+    ulid.to_uuid()
+  }
+}
+
+impl TryFrom<Uuid> for Ulid {
+  type Error = UuidError;
+
+  fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+    if uuid.get_version_num() != 7 || uuid.get_variant() != Variant::RFC4122 {
+      return Err(UuidError::new(uuid));
+    }
+
+    Ok(Ulid::from_uuid(uuid))
+  }
+}
+
+/// The error that can occur when converting a [`Uuid`] that is not a UUIDv7 into a [`Ulid`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UuidError {
+  found: Uuid,
+}
+
+impl UuidError {
+  /// Create a new [`UuidError`].
+  pub const fn new(found: Uuid) -> Self {
+    UuidError { found }
+  }
+
+  /// The [`Uuid`] that was found.
+  pub const fn found(&self) -> Uuid {
+    self.found
   }
 }